@@ -0,0 +1,84 @@
+//! Compare the Levenshtein-automaton suggestion search with the previous prefix scan.
+//!
+//! Runs against a generated fixture dictionary, so no installed dictionary is needed.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use spellchk::checker::dictionary::Dictionary;
+use spellchk::checker::keyboard::KeyboardLayout;
+use spellchk::checker::suggestions;
+use std::collections::HashMap;
+
+const TYPOS: &[&str] = &[
+    "teh",
+    "wrod",
+    "ppeople",
+    "recieve",
+    "seperate",
+    "acommodation",
+];
+
+/// The words the typos are meant to be, so every search has real candidates
+const WORDS: &[&str] = &[
+    "the",
+    "word",
+    "people",
+    "receive",
+    "separate",
+    "accommodation",
+];
+
+const SYLLABLES: &[&str] = &[
+    "ac", "com", "mo", "da", "tion", "re", "ceive", "sep", "ar", "ate", "peo", "ple", "wo", "rd",
+    "te", "he", "in", "ter", "un", "der", "pro", "con", "ment", "al", "ly", "er", "ing", "ed",
+    "es", "ous",
+];
+
+/// A dictionary of real and generated words with frequencies, about 27,000 entries
+fn fixture_dictionary(dir: &std::path::Path) -> Dictionary {
+    let mut frequencies: HashMap<String, u64> = HashMap::new();
+    for (rank, word) in WORDS.iter().enumerate() {
+        frequencies.insert(word.to_string(), 1_000_000 / (rank as u64 + 1));
+    }
+    for (i, a) in SYLLABLES.iter().enumerate() {
+        for (j, b) in SYLLABLES.iter().enumerate() {
+            frequencies.insert(format!("{}{}", a, b), (i * j) as u64);
+            for (k, c) in SYLLABLES.iter().enumerate() {
+                frequencies.insert(format!("{}{}{}", a, b, c), ((i + j + k) % 97) as u64);
+            }
+        }
+    }
+
+    let dict_path = dir.join("bench.dict");
+    let words: Vec<String> = frequencies.keys().cloned().collect();
+    Dictionary::build_with_frequencies(&words, &frequencies, &dict_path)
+        .expect("failed to build fixture dictionary");
+    Dictionary::load_from_path(&dict_path).expect("failed to load fixture dictionary")
+}
+
+fn bench_suggestions(c: &mut Criterion) {
+    let temp_dir = tempfile::TempDir::new().expect("failed to create temp dir");
+    let dictionary = fixture_dictionary(temp_dir.path());
+    let mut group = c.benchmark_group("suggestions");
+
+    for typo in TYPOS {
+        group.bench_with_input(BenchmarkId::new("automaton", typo), typo, |b, typo| {
+            b.iter(|| {
+                suggestions::generate(
+                    black_box(typo),
+                    &dictionary,
+                    KeyboardLayout::default(),
+                    None,
+                    5,
+                )
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("prefix_scan", typo), typo, |b, typo| {
+            b.iter(|| suggestions::generate_by_prefix(black_box(typo), &dictionary, 5))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_suggestions);
+criterion_main!(benches);
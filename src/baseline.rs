@@ -0,0 +1,275 @@
+use crate::SpellError;
+use anyhow::{Context, Result};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Mutex;
+
+lazy_static! {
+    // Keys are relative to the repository root, so runs from any directory match
+    static ref REPO_ROOT: Option<PathBuf> = crate::git::repo_root().ok();
+}
+
+pub const DEFAULT_BASELINE_FILE: &str = ".spellchk-baseline.json";
+
+const BASELINE_VERSION: u32 = 1;
+
+/// A known finding, identified by the content of its line rather than its position
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub file: String,
+    pub word: String,
+    pub fingerprint: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Baseline {
+    pub version: u32,
+    pub entries: Vec<BaselineEntry>,
+}
+
+impl Default for Baseline {
+    fn default() -> Self {
+        Self {
+            version: BASELINE_VERSION,
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read baseline: {}", path.display()))?;
+        let baseline: Self = serde_json::from_str(&contents)
+            .with_context(|| format!("Failed to parse baseline: {}", path.display()))?;
+
+        if baseline.version != BASELINE_VERSION {
+            anyhow::bail!(
+                "Unsupported baseline version {} in {}",
+                baseline.version,
+                path.display()
+            );
+        }
+
+        Ok(baseline)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut baseline = self.clone();
+        baseline.entries.sort();
+
+        let json = serde_json::to_string_pretty(&baseline)?;
+        fs::write(path, json + "\n")
+            .with_context(|| format!("Failed to write baseline: {}", path.display()))
+    }
+
+    /// Record the findings of one file
+    pub fn add(&mut self, file: &Path, content: &str, errors: &[SpellError]) {
+        let file = file_key(file);
+        for error in errors {
+            self.entries.push(BaselineEntry {
+                file: file.clone(),
+                word: error.word.clone(),
+                fingerprint: fingerprint(content, error.line),
+            });
+        }
+    }
+}
+
+/// Suppresses baseline findings during a run and tracks which entries went unused
+pub struct BaselineFilter {
+    remaining: Mutex<HashMap<BaselineEntry, usize>>,
+    visited: Mutex<HashSet<String>>,
+}
+
+impl BaselineFilter {
+    pub fn new(baseline: Baseline) -> Self {
+        let mut remaining = HashMap::new();
+        for entry in baseline.entries {
+            *remaining.entry(entry).or_insert(0) += 1;
+        }
+
+        Self {
+            remaining: Mutex::new(remaining),
+            visited: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Drop errors recorded in the baseline, returning only new findings
+    pub fn suppress(&self, file: &Path, content: &str, errors: Vec<SpellError>) -> Vec<SpellError> {
+        self.suppress_by(file, content, errors, |error| error)
+    }
+
+    /// `suppress` for items that carry an error, such as findings with their offsets
+    pub fn suppress_by<T>(
+        &self,
+        file: &Path,
+        content: &str,
+        items: Vec<T>,
+        error_of: impl Fn(&T) -> &SpellError,
+    ) -> Vec<T> {
+        let file = file_key(file);
+        let mut remaining = self.remaining.lock().unwrap();
+
+        let items = items
+            .into_iter()
+            .filter(|item| {
+                let error = error_of(item);
+                let entry = BaselineEntry {
+                    file: file.clone(),
+                    word: error.word.clone(),
+                    fingerprint: fingerprint(content, error.line),
+                };
+                match remaining.get_mut(&entry) {
+                    Some(count) if *count > 0 => {
+                        *count -= 1;
+                        false
+                    }
+                    _ => true,
+                }
+            })
+            .collect();
+
+        self.visited.lock().unwrap().insert(file);
+        items
+    }
+
+    /// Baseline entries of checked files that no longer match any finding
+    pub fn stale_entries(&self) -> Vec<BaselineEntry> {
+        let remaining = self.remaining.lock().unwrap();
+        let visited = self.visited.lock().unwrap();
+
+        let mut stale: Vec<BaselineEntry> = remaining
+            .iter()
+            .filter(|(entry, &count)| count > 0 && visited.contains(&entry.file))
+            .flat_map(|(entry, &count)| std::iter::repeat_n(entry.clone(), count))
+            .collect();
+        stale.sort();
+        stale
+    }
+}
+
+/// Hash of a line's whitespace-normalized content, stable when the line moves
+pub fn fingerprint(content: &str, line: usize) -> String {
+    let text = content.lines().nth(line.saturating_sub(1)).unwrap_or("");
+    let normalized = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let digest = Sha256::digest(normalized.as_bytes());
+    digest[..8].iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Normalize a path so baselines are portable across platforms and working directories
+fn file_key(path: &Path) -> String {
+    key_relative_to(REPO_ROOT.as_deref(), path)
+}
+
+/// Key a path relative to `root`, or as given when it is outside `root` or doesn't exist
+fn key_relative_to(root: Option<&Path>, path: &Path) -> String {
+    let relative = root.and_then(|root| {
+        let absolute = path.canonicalize().ok()?;
+        absolute.strip_prefix(root).ok().map(Path::to_path_buf)
+    });
+    relative
+        .as_deref()
+        .unwrap_or(path)
+        .components()
+        .filter(|c| !matches!(c, Component::CurDir))
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ErrorKind, Severity};
+
+    fn error(word: &str, line: usize) -> SpellError {
+        SpellError {
+            word: word.to_string(),
+            line,
+            column: 1,
+            context: String::new(),
+            suggestions: Vec::new(),
+            kind: ErrorKind::Misspelling,
+            severity: Severity::Error,
+            confidence: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_position() {
+        let before = "intro\nsome mispeled  text\n";
+        let after = "intro\nnew line\n    some mispeled text\n";
+        assert_eq!(fingerprint(before, 2), fingerprint(after, 3));
+        assert_ne!(fingerprint(before, 1), fingerprint(before, 2));
+    }
+
+    #[test]
+    fn test_suppress_and_stale() {
+        let content = "one mispeled\ntwo wrng\n";
+        let mut baseline = Baseline::default();
+        baseline.add(
+            Path::new("./doc.md"),
+            content,
+            &[error("mispeled", 1), error("wrng", 2)],
+        );
+
+        let filter = BaselineFilter::new(baseline);
+        let updated = "one mispeled\ntwo fixed\nthree nwe\n";
+        let errors = filter.suppress(
+            Path::new("doc.md"),
+            updated,
+            vec![error("mispeled", 1), error("nwe", 3)],
+        );
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].word, "nwe");
+
+        let stale = filter.stale_entries();
+        assert_eq!(stale.len(), 1);
+        assert_eq!(stale[0].word, "wrng");
+    }
+
+    #[test]
+    fn test_keys_relative_to_root() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        fs::create_dir_all(root.join("docs")).unwrap();
+        fs::write(root.join("docs/guide.md"), "").unwrap();
+
+        let via_subdir = root.join("docs/../docs/./guide.md");
+        assert_eq!(key_relative_to(Some(&root), &via_subdir), "docs/guide.md");
+        assert_eq!(
+            key_relative_to(Some(&root), &root.join("docs/guide.md")),
+            "docs/guide.md"
+        );
+
+        // Paths that don't resolve inside the root keep their own form
+        assert_eq!(
+            key_relative_to(Some(&root), Path::new("./<stdin>")),
+            "<stdin>"
+        );
+        assert_eq!(key_relative_to(None, Path::new("./a/b.md")), "a/b.md");
+    }
+
+    #[test]
+    fn test_suppress_by_keeps_items() {
+        let content = "teh cat
+";
+        let mut baseline = Baseline::default();
+        baseline.add(Path::new("b.txt"), content, &[error("teh", 1)]);
+
+        let filter = BaselineFilter::new(baseline);
+        let findings = vec![(0, 3, error("teh", 1)), (8, 11, error("teh", 1))];
+        let kept = filter.suppress_by(Path::new("b.txt"), content, findings, |f| &f.2);
+        assert_eq!(
+            kept.iter().map(|f| (f.0, f.1)).collect::<Vec<_>>(),
+            vec![(8, 11)]
+        );
+    }
+}
@@ -1,11 +1,20 @@
+use crate::checker::phonetic::{self, PhoneticEncoder};
 use anyhow::{Context, Result};
-use fst::{Automaton, IntoStreamer, Set, SetBuilder, Streamer};
+use fst::automaton::Levenshtein;
+use fst::{Automaton, IntoStreamer, Map, MapBuilder, Set, Streamer};
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Read};
 use std::path::{Path, PathBuf};
 
 pub struct Dictionary {
-    set: Set<Vec<u8>>,
+    /// Words mapped to their corpus frequency (0 when unknown)
+    words: Map<Vec<u8>>,
+    nosuggest: Option<Set<Vec<u8>>>,
+    /// Phonetic code + NUL + word, for sound-alike lookups
+    phonetic: Option<Set<Vec<u8>>>,
+    /// Entries written with capitals, keyed by their lowercase form
+    canonical: HashMap<String, Vec<String>>,
 }
 
 impl Dictionary {
@@ -23,58 +32,191 @@ impl Dictionary {
 
     /// Load dictionary from a specific path (useful for testing)
     pub fn load_from_path(path: &Path) -> Result<Self> {
+        let words = Map::new(Self::read_bytes(path)?).context("Failed to parse dictionary")?;
+
+        // Words that are accepted but never suggested live in an optional sidecar set
+        let nosuggest = Self::read_sidecar(&Self::nosuggest_path(path))?;
+        let phonetic = Self::read_sidecar(&Self::phonetic_path(path))?;
+
+        let mut canonical: HashMap<String, Vec<String>> = HashMap::new();
+        if let Some(set) = Self::read_sidecar(&Self::canonical_path(path))? {
+            let mut stream = set.stream();
+            while let Some(key) = stream.next() {
+                if let Ok(form) = String::from_utf8(key.to_vec()) {
+                    canonical.entry(form.to_lowercase()).or_default().push(form);
+                }
+            }
+        }
+
+        Ok(Self {
+            words,
+            nosuggest,
+            phonetic,
+            canonical,
+        })
+    }
+
+    fn read_sidecar(path: &Path) -> Result<Option<Set<Vec<u8>>>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let set = Set::new(Self::read_bytes(path)?)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        Ok(Some(set))
+    }
+
+    fn read_bytes(path: &Path) -> Result<Vec<u8>> {
         let file = File::open(path)
             .with_context(|| format!("Failed to open dictionary: {}", path.display()))?;
 
         let reader = BufReader::new(file);
-        let set = Set::new(reader.bytes().collect::<Result<Vec<_>, _>>()?)
-            .context("Failed to parse dictionary")?;
+        Ok(reader.bytes().collect::<Result<Vec<_>, _>>()?)
+    }
 
-        Ok(Self { set })
+    /// Path of the NOSUGGEST sidecar set for a dictionary file
+    pub fn nosuggest_path(dict_path: &Path) -> PathBuf {
+        dict_path.with_extension("nosuggest")
+    }
+
+    /// Path of the canonical casing sidecar for a dictionary file
+    pub fn canonical_path(dict_path: &Path) -> PathBuf {
+        dict_path.with_extension("canonical")
+    }
+
+    /// Path of the phonetic index sidecar for a dictionary file
+    pub fn phonetic_path(dict_path: &Path) -> PathBuf {
+        dict_path.with_extension("phonetic")
     }
 
     /// Check if word exists in dictionary
     pub fn contains(&self, word: &str) -> bool {
-        self.set.contains(word.as_bytes())
+        self.words.contains_key(word.as_bytes())
+    }
+
+    /// Spellings with capitals of a lowercased word, if the dictionary lists it that way
+    pub fn canonical(&self, word: &str) -> &[String] {
+        self.canonical
+            .get(word)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Corpus frequency of a word, or 0 if unknown
+    pub fn frequency(&self, word: &str) -> u64 {
+        self.words.get(word.as_bytes()).unwrap_or(0)
+    }
+
+    /// Check if word may be offered as a suggestion
+    pub fn is_suggestible(&self, word: &str) -> bool {
+        !self
+            .nosuggest
+            .as_ref()
+            .is_some_and(|nosuggest| nosuggest.contains(word.as_bytes()))
     }
 
-    /// Get all words with a given prefix
+    /// Get all suggestible words with a given prefix
     pub fn words_with_prefix(&self, prefix: &str) -> Vec<String> {
         let mut results = Vec::new();
         let mut stream = self
-            .set
+            .words
             .search(fst::automaton::Str::new(prefix).starts_with())
             .into_stream();
 
-        while let Some(key) = stream.next() {
+        while let Some((key, _)) = stream.next() {
+            if let Ok(word) = String::from_utf8(key.to_vec()) {
+                if self.is_suggestible(&word) {
+                    results.push(word);
+                }
+            }
+        }
+
+        results
+    }
+
+    /// Suggestible words within `distance` Levenshtein edits of `word`
+    ///
+    /// Returns `None` if the automaton for `word` would be too large to build.
+    pub fn fuzzy_search(&self, word: &str, distance: u32) -> Option<Vec<String>> {
+        let automaton = Levenshtein::new(word, distance).ok()?;
+        let mut results = Vec::new();
+        let mut stream = self.words.search(automaton).into_stream();
+
+        while let Some((key, _)) = stream.next() {
             if let Ok(word) = String::from_utf8(key.to_vec()) {
-                results.push(word);
+                if self.is_suggestible(&word) {
+                    results.push(word);
+                }
+            }
+        }
+
+        Some(results)
+    }
+
+    /// Suggestible words sharing one of the phonetic `codes` (empty without an index)
+    pub fn sounds_like(&self, codes: &[String]) -> Vec<String> {
+        let Some(index) = &self.phonetic else {
+            return Vec::new();
+        };
+
+        let mut results = Vec::new();
+        for code in codes {
+            let prefix = format!("{}\0", code);
+            let mut stream = index
+                .search(fst::automaton::Str::new(&prefix).starts_with())
+                .into_stream();
+
+            while let Some(key) = stream.next() {
+                if let Ok(word) = std::str::from_utf8(&key[prefix.len()..]) {
+                    if self.is_suggestible(word) && !results.iter().any(|r| r == word) {
+                        results.push(word.to_string());
+                    }
+                }
             }
         }
 
         results
     }
 
-    /// Get all words in dictionary (for building suggestions)
+    /// Get all suggestible words in dictionary (for building suggestions)
     ///
     /// WARNING: This is an expensive operation that loads the entire dictionary
     /// into memory. Use sparingly and consider caching the result if called multiple times.
     /// Prefer using `words_with_prefix()` or direct `contains()` checks when possible.
     pub fn all_words(&self) -> Vec<String> {
         let mut words = Vec::new();
-        let mut stream = self.set.stream();
+        let mut stream = self.words.keys();
 
         while let Some(key) = stream.next() {
             if let Ok(word) = String::from_utf8(key.to_vec()) {
-                words.push(word);
+                if self.is_suggestible(&word) {
+                    words.push(word);
+                }
             }
         }
 
         words
     }
 
-    /// Build dictionary from word list
-    pub fn build_from_words(words: &[String], output_path: &Path) -> Result<()> {
+    /// Number of words in the dictionary
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Build dictionary from word list, returning the number of unique words
+    pub fn build_from_words(words: &[String], output_path: &Path) -> Result<usize> {
+        Self::build_with_frequencies(words, &HashMap::new(), output_path)
+    }
+
+    /// Build dictionary storing each word's frequency, returning the number of unique words
+    pub fn build_with_frequencies(
+        words: &[String],
+        frequencies: &HashMap<String, u64>,
+        output_path: &Path,
+    ) -> Result<usize> {
         let mut sorted_words = words.to_vec();
         sorted_words.sort();
         sorted_words.dedup();
@@ -83,17 +225,37 @@ impl Dictionary {
             .with_context(|| format!("Failed to create dictionary: {}", output_path.display()))?;
 
         let writer = BufWriter::new(file);
-        let mut builder = SetBuilder::new(writer).context("Failed to create FST builder")?;
+        let mut builder = MapBuilder::new(writer).context("Failed to create FST builder")?;
 
-        for word in sorted_words {
+        for word in &sorted_words {
+            let frequency = frequencies.get(word).copied().unwrap_or(0);
             builder
-                .insert(word.as_bytes())
+                .insert(word.as_bytes(), frequency)
                 .context("Failed to insert word into dictionary")?;
         }
 
         builder.finish().context("Failed to finalize dictionary")?;
 
-        Ok(())
+        Ok(sorted_words.len())
+    }
+
+    /// Build the phonetic index sidecar, returning the number of entries
+    pub fn build_phonetic_index(
+        words: &[String],
+        encoder: &dyn PhoneticEncoder,
+        output_path: &Path,
+    ) -> Result<usize> {
+        let keys: Vec<String> = words
+            .iter()
+            .flat_map(|word| {
+                encoder
+                    .encode(word)
+                    .into_iter()
+                    .map(move |code| format!("{}\0{}", code, word))
+            })
+            .collect();
+
+        Self::build_from_words(&keys, output_path)
     }
 
     fn get_dictionary_path(language: &str) -> Result<PathBuf> {
@@ -112,6 +274,13 @@ impl Dictionary {
 
         let dict_path = Self::get_dictionary_path(language)?;
         Self::build_from_words(&basic_words, &dict_path)?;
+        if let Some(encoder) = phonetic::encoder_for(language) {
+            Self::build_phonetic_index(
+                &basic_words,
+                encoder.as_ref(),
+                &Self::phonetic_path(&dict_path),
+            )?;
+        }
 
         Self::load(language)
     }
@@ -289,5 +458,61 @@ mod tests {
         assert!(dict.contains("hello"));
         assert!(dict.contains("world"));
         assert!(!dict.contains("notfound"));
+        assert_eq!(dict.len(), 3);
+        assert_eq!(dict.frequency("hello"), 0);
+    }
+
+    #[test]
+    fn test_frequencies() {
+        let dir = tempdir().unwrap();
+        let dict_path = dir.path().join("test.dict");
+
+        let words = vec!["the".to_string(), "thew".to_string()];
+        let frequencies = HashMap::from([("the".to_string(), 5000), ("cat".to_string(), 10)]);
+        Dictionary::build_with_frequencies(&words, &frequencies, &dict_path).unwrap();
+
+        let dict = Dictionary::load_from_path(&dict_path).unwrap();
+        assert_eq!(dict.frequency("the"), 5000);
+        assert_eq!(dict.frequency("thew"), 0);
+        assert!(!dict.contains("cat"));
+    }
+
+    #[test]
+    fn test_nosuggest_sidecar() {
+        let dir = tempdir().unwrap();
+        let dict_path = dir.path().join("test.dict");
+
+        let words = vec!["damn".to_string(), "dame".to_string()];
+        Dictionary::build_from_words(&words, &dict_path).unwrap();
+        Dictionary::build_from_words(
+            &["damn".to_string()],
+            &Dictionary::nosuggest_path(&dict_path),
+        )
+        .unwrap();
+
+        let dict = Dictionary::load_from_path(&dict_path).unwrap();
+        assert!(dict.contains("damn"));
+        assert!(!dict.is_suggestible("damn"));
+        assert_eq!(dict.words_with_prefix("dam"), vec!["dame"]);
+    }
+
+    #[test]
+    fn test_phonetic_sidecar() {
+        let dir = tempdir().unwrap();
+        let dict_path = dir.path().join("test.dict");
+
+        let words = vec!["phonetic".to_string(), "fanatic".to_string()];
+        Dictionary::build_from_words(&words, &dict_path).unwrap();
+        let dict = Dictionary::load_from_path(&dict_path).unwrap();
+        assert!(dict.sounds_like(&["FNTK".to_string()]).is_empty());
+
+        let encoder = phonetic::DoubleMetaphone::default();
+        Dictionary::build_phonetic_index(&words, &encoder, &Dictionary::phonetic_path(&dict_path))
+            .unwrap();
+        let dict = Dictionary::load_from_path(&dict_path).unwrap();
+        assert_eq!(
+            dict.sounds_like(&encoder.encode("fonetik")),
+            vec!["fanatic", "phonetic"]
+        );
     }
 }
@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+/// Physical keyboard layout, used to weigh slips onto neighbouring keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyboardLayout {
+    #[default]
+    Qwerty,
+    Qwertz,
+    Azerty,
+    Dvorak,
+}
+
+impl KeyboardLayout {
+    /// Character rows from top to bottom; each row sits half a key right of the one above
+    fn rows(&self) -> [&'static str; 3] {
+        match self {
+            KeyboardLayout::Qwerty => ["qwertyuiop", "asdfghjkl", "zxcvbnm"],
+            KeyboardLayout::Qwertz => ["qwertzuiopü", "asdfghjklöä", "yxcvbnm"],
+            KeyboardLayout::Azerty => ["azertyuiop", "qsdfghjklm", "wxcvbn"],
+            // Punctuation keeps the letters in their physical columns
+            KeyboardLayout::Dvorak => ["',.pyfgcrl", "aoeuidhtns", ";qjkxbmwvz"],
+        }
+    }
+
+    fn position(&self, key: char) -> Option<(usize, usize)> {
+        self.rows()
+            .iter()
+            .enumerate()
+            .find_map(|(row, keys)| keys.chars().position(|k| k == key).map(|col| (row, col)))
+    }
+
+    /// Whether two keys touch on this layout
+    pub fn are_adjacent(&self, a: char, b: char) -> bool {
+        let (Some((row_a, col_a)), Some((row_b, col_b))) = (self.position(a), self.position(b))
+        else {
+            return false;
+        };
+
+        match row_a as isize - row_b as isize {
+            0 => col_a.abs_diff(col_b) == 1,
+            // The lower key touches the key above it and the one up and to the right
+            1 => col_b == col_a || col_b == col_a + 1,
+            -1 => col_a == col_b || col_a == col_b + 1,
+            _ => false,
+        }
+    }
+
+    /// Letters on keys touching `key`
+    pub fn neighbours(&self, key: char) -> Vec<char> {
+        self.rows()
+            .iter()
+            .flat_map(|keys| keys.chars())
+            .filter(|&other| other.is_alphabetic() && self.are_adjacent(key, other))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adjacency_per_layout() {
+        let qwerty = KeyboardLayout::Qwerty;
+        assert!(qwerty.are_adjacent('e', 'd'));
+        assert!(qwerty.are_adjacent('d', 'e'));
+        assert!(qwerty.are_adjacent('a', 'z'));
+        assert!(!qwerty.are_adjacent('e', 'c'));
+        assert!(!qwerty.are_adjacent('z', 'u'));
+
+        assert!(KeyboardLayout::Qwertz.are_adjacent('z', 'u'));
+        assert!(KeyboardLayout::Azerty.are_adjacent('a', 'z'));
+        assert!(KeyboardLayout::Azerty.are_adjacent('m', 'l'));
+        assert!(KeyboardLayout::Dvorak.are_adjacent('e', 'u'));
+        assert!(!KeyboardLayout::Dvorak.are_adjacent('e', 'd'));
+
+        let mut neighbours = qwerty.neighbours('s');
+        neighbours.sort();
+        assert_eq!(neighbours, vec!['a', 'd', 'e', 'w', 'x', 'z']);
+    }
+}
@@ -0,0 +1,324 @@
+use crate::checker::dictionary::Dictionary;
+use crate::config::{Config, DictionaryLayer};
+use crate::dict::sources::sha256_hex;
+use anyhow::{Context, Result};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// What a layer says about a word
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Accepted,
+    Rejected,
+}
+
+enum Words {
+    Memory(HashSet<String>),
+    Compiled(Dictionary),
+}
+
+/// A named word list; entries prefixed with `!` reject a word instead of accepting it
+pub struct Layer {
+    pub name: String,
+    accepted: Words,
+    rejected: HashSet<String>,
+    /// Entries written with capitals, keyed by their lowercase form
+    canonical: HashMap<String, Vec<String>>,
+}
+
+impl Layer {
+    /// Build an in-memory layer from accepted words
+    pub fn from_words(name: impl Into<String>, words: impl IntoIterator<Item = String>) -> Self {
+        let words: Vec<String> = words.into_iter().collect();
+        Self {
+            name: name.into(),
+            canonical: canonical_forms(&words),
+            accepted: Words::Memory(words.iter().map(|w| w.to_lowercase()).collect()),
+            rejected: HashSet::new(),
+        }
+    }
+
+    /// Load a word-list file, compiling it to a cached FST if requested
+    pub fn load(layer: &DictionaryLayer) -> Result<Self> {
+        let content = fs::read_to_string(&layer.path).with_context(|| {
+            format!(
+                "Failed to read dictionary layer '{}': {}",
+                layer.name,
+                layer.path.display()
+            )
+        })?;
+        let (accepted, rejected) = parse_word_list(&content);
+        let canonical = canonical_forms(&accepted);
+        let accepted: Vec<String> = accepted.iter().map(|w| w.to_lowercase()).collect();
+
+        let accepted = if layer.compile {
+            Words::Compiled(compile(&layer.path, &content, accepted)?)
+        } else {
+            Words::Memory(accepted.into_iter().collect())
+        };
+
+        Ok(Self {
+            name: layer.name.clone(),
+            accepted,
+            rejected: rejected.into_iter().collect(),
+            canonical,
+        })
+    }
+
+    /// The layer's verdict on a lowercased word, if it has one
+    pub fn verdict(&self, word: &str) -> Option<Verdict> {
+        if self.rejected.contains(word) {
+            return Some(Verdict::Rejected);
+        }
+
+        let accepted = match &self.accepted {
+            Words::Memory(words) => words.contains(word),
+            Words::Compiled(dictionary) => dictionary.contains(word),
+        };
+        accepted.then_some(Verdict::Accepted)
+    }
+
+    /// Canonical spellings of a lowercased word, if the layer lists it with capitals
+    pub fn canonical(&self, word: &str) -> &[String] {
+        self.canonical
+            .get(word)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+}
+
+/// Ordered layers; the first one with a verdict on a word decides it
+#[derive(Default)]
+pub struct DictionaryStack {
+    layers: Vec<Layer>,
+}
+
+impl DictionaryStack {
+    pub fn push(&mut self, layer: Layer) {
+        self.layers.push(layer);
+    }
+
+    /// The deciding layer's name and verdict, if any layer knows the word
+    pub fn lookup(&self, word: &str) -> Option<(&str, Verdict)> {
+        self.layers
+            .iter()
+            .find_map(|layer| layer.verdict(word).map(|v| (layer.name.as_str(), v)))
+    }
+
+    /// Canonical spellings of a lowercased word across all layers
+    pub fn canonical_forms(&self, word: &str) -> Vec<&str> {
+        let mut forms: Vec<&str> = Vec::new();
+        for form in self.layers.iter().flat_map(|layer| layer.canonical(word)) {
+            if !forms.contains(&form.as_str()) {
+                forms.push(form);
+            }
+        }
+        forms
+    }
+
+    /// Every layer's verdict on a word, in lookup order
+    pub fn verdicts(&self, word: &str) -> Vec<(&str, Option<Verdict>)> {
+        self.layers
+            .iter()
+            .map(|layer| (layer.name.as_str(), layer.verdict(word)))
+            .collect()
+    }
+}
+
+/// Group entries containing capitals (e.g., "GitHub") by their lowercase form
+fn canonical_forms(words: &[String]) -> HashMap<String, Vec<String>> {
+    let mut canonical: HashMap<String, Vec<String>> = HashMap::new();
+    for word in words.iter().filter(|w| w.chars().any(char::is_uppercase)) {
+        let forms = canonical.entry(word.to_lowercase()).or_default();
+        if !forms.contains(word) {
+            forms.push(word.clone());
+        }
+    }
+    canonical
+}
+
+/// Split a word list into accepted (casing preserved) and rejected (`!word`) entries
+fn parse_word_list(content: &str) -> (Vec<String>, Vec<String>) {
+    let mut accepted = Vec::new();
+    let mut rejected = Vec::new();
+
+    for line in content.lines() {
+        let word = line.trim();
+        if word.is_empty() || word.starts_with('#') {
+            continue;
+        }
+        match word.strip_prefix('!') {
+            Some(word) => rejected.push(word.trim().to_lowercase()),
+            None => accepted.push(word.to_string()),
+        }
+    }
+
+    (accepted, rejected)
+}
+
+/// Load the FST for a word list from the cache, building it when the list changed
+fn compile(source: &Path, content: &str, words: Vec<String>) -> Result<Dictionary> {
+    let cache_dir = Config::cache_dir()
+        .context("Failed to get cache directory")?
+        .join("layers");
+    compile_in(&cache_dir, source, content, words)
+}
+
+fn compile_in(
+    cache_dir: &Path,
+    source: &Path,
+    content: &str,
+    words: Vec<String>,
+) -> Result<Dictionary> {
+    fs::create_dir_all(cache_dir).context("Failed to create cache directory")?;
+
+    let path = compiled_path(cache_dir, source, content);
+    if !path.exists() {
+        Dictionary::build_from_words(&words, &path)?;
+        remove_stale(cache_dir, &path)?;
+    }
+
+    Dictionary::load_from_path(&path)
+}
+
+/// Cached FSTs are keyed by the word list's path and content
+fn compiled_path(cache_dir: &Path, source: &Path, content: &str) -> PathBuf {
+    let source = source
+        .canonicalize()
+        .unwrap_or_else(|_| source.to_path_buf());
+    let source_key = sha256_hex(source.to_string_lossy().as_bytes());
+    cache_dir.join(format!(
+        "{}-{}.dict",
+        &source_key[..16],
+        sha256_hex(content.as_bytes())
+    ))
+}
+
+/// Remove FSTs built from earlier contents of the same word list
+fn remove_stale(cache_dir: &Path, current: &Path) -> Result<()> {
+    let Some(prefix) = current
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.split_once('-'))
+        .map(|(source_key, _)| format!("{}-", source_key))
+    else {
+        return Ok(());
+    };
+
+    for entry in fs::read_dir(cache_dir).context("Failed to read cache directory")? {
+        let path = entry?.path();
+        let stale = path != current
+            && path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(&prefix));
+        if stale {
+            fs::remove_file(&path)
+                .with_context(|| format!("Failed to remove {}", path.display()))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_first_layer_with_verdict_wins() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("project.txt");
+        fs::write(&path, "# project words\nTokio\n!teh\n").unwrap();
+
+        let mut stack = DictionaryStack::default();
+        stack.push(
+            Layer::load(&DictionaryLayer {
+                name: "project".to_string(),
+                path,
+                compile: false,
+            })
+            .unwrap(),
+        );
+        stack.push(Layer::from_words(
+            "team",
+            vec!["teh".to_string(), "serde".to_string()],
+        ));
+
+        assert_eq!(stack.lookup("tokio"), Some(("project", Verdict::Accepted)));
+        assert_eq!(stack.lookup("teh"), Some(("project", Verdict::Rejected)));
+        assert_eq!(stack.lookup("serde"), Some(("team", Verdict::Accepted)));
+        assert_eq!(stack.lookup("unknown"), None);
+        assert_eq!(
+            stack.verdicts("teh"),
+            vec![
+                ("project", Some(Verdict::Rejected)),
+                ("team", Some(Verdict::Accepted))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_canonical_forms() {
+        let mut stack = DictionaryStack::default();
+        stack.push(Layer::from_words(
+            "brands",
+            vec!["GitHub".to_string(), "kubernetes".to_string()],
+        ));
+
+        assert_eq!(stack.lookup("github"), Some(("brands", Verdict::Accepted)));
+        assert_eq!(stack.canonical_forms("github"), vec!["GitHub"]);
+        assert!(stack.canonical_forms("kubernetes").is_empty());
+    }
+
+    #[test]
+    fn test_compiled_path_tracks_content() {
+        let dir = Path::new("/cache");
+        let source = Path::new("/words/team.txt");
+        assert_eq!(
+            compiled_path(dir, source, "a\nb\n"),
+            compiled_path(dir, source, "a\nb\n")
+        );
+        assert_ne!(
+            compiled_path(dir, source, "a\n"),
+            compiled_path(dir, source, "b\n")
+        );
+        assert_ne!(
+            compiled_path(dir, source, "a\n"),
+            compiled_path(dir, Path::new("/words/other.txt"), "a\n")
+        );
+    }
+
+    #[test]
+    fn test_compile_removes_stale_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache_dir = temp_dir.path().join("layers");
+        let team = temp_dir.path().join("team.txt");
+        let other = temp_dir.path().join("other.txt");
+        let words = |list: &[&str]| list.iter().map(|w| w.to_string()).collect::<Vec<_>>();
+
+        compile_in(&cache_dir, &other, "serde\n", words(&["serde"])).unwrap();
+        compile_in(&cache_dir, &team, "tokio\n", words(&["tokio"])).unwrap();
+        let dictionary = compile_in(
+            &cache_dir,
+            &team,
+            "tokio\naxum\n",
+            words(&["tokio", "axum"]),
+        )
+        .unwrap();
+        assert!(dictionary.contains("axum"));
+
+        let mut cached: Vec<PathBuf> = fs::read_dir(&cache_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        cached.sort();
+        let mut expected = vec![
+            compiled_path(&cache_dir, &other, "serde\n"),
+            compiled_path(&cache_dir, &team, "tokio\naxum\n"),
+        ];
+        expected.sort();
+        assert_eq!(cached, expected);
+    }
+}
@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+
+/// Curated typo → correction pairs, in codespell's `typo->correction` format
+const COMMON_MISSPELLINGS: &str = include_str!("misspellings.txt");
+
+/// Known misspellings with their corrections, consulted before the dictionary
+pub struct Misspellings {
+    table: HashMap<String, Vec<String>>,
+}
+
+impl Misspellings {
+    /// Built-in table extended with configured `typo = "correction"` pairs
+    pub fn new(extra: &HashMap<String, String>) -> Self {
+        let mut table: HashMap<String, Vec<String>> = COMMON_MISSPELLINGS
+            .lines()
+            .filter_map(|line| line.split_once("->"))
+            .map(|(typo, corrections)| (typo.trim().to_lowercase(), split(corrections)))
+            .collect();
+
+        for (typo, corrections) in extra {
+            table.insert(typo.to_lowercase(), split(corrections));
+        }
+
+        Self { table }
+    }
+
+    /// Corrections for a lowercased word; more than one means the fix is ambiguous
+    pub fn corrections(&self, word: &str) -> Option<&[String]> {
+        self.table.get(word).map(Vec::as_slice)
+    }
+}
+
+fn split(corrections: &str) -> Vec<String> {
+    corrections
+        .split(',')
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_and_configured_entries() {
+        let extra = HashMap::from([("Kubernates".to_string(), "kubernetes".to_string())]);
+        let misspellings = Misspellings::new(&extra);
+
+        assert_eq!(
+            misspellings.corrections("recieve"),
+            Some(&["receive".to_string()][..])
+        );
+        assert_eq!(misspellings.corrections("wich").map(<[_]>::len), Some(2));
+        assert_eq!(
+            misspellings.corrections("kubernates"),
+            Some(&["kubernetes".to_string()][..])
+        );
+        assert_eq!(misspellings.corrections("receive"), None);
+    }
+}
@@ -1,20 +1,70 @@
 pub mod dictionary;
+pub mod keyboard;
+pub mod layers;
+pub mod misspellings;
+pub mod packs;
+pub mod phonetic;
+pub mod rules;
 pub mod suggestions;
+pub mod terminology;
 pub mod tokenizer;
+pub mod variants;
 
+use crate::baseline::{Baseline, BaselineEntry, BaselineFilter};
 use crate::cli::output::{print_errors, print_interactive_prompt, OutputFormat};
-use crate::{CheckResult, Config, SpellError};
+use crate::git::{ChangedLines, FileChanges};
+use crate::parser::directives::{self, Directives};
+use crate::parser::{FileType, TextSpan};
+use crate::{CheckResult, Config, ErrorKind, Severity, SpellError};
 use anyhow::{Context, Result};
 use dictionary::Dictionary;
+use keyboard::KeyboardLayout;
+use layers::{DictionaryStack, Layer, Verdict};
+use misspellings::Misspellings;
+use phonetic::PhoneticEncoder;
 use regex::Regex;
-use std::collections::HashSet;
+use rules::{ConfiguredRule, RuleContext};
 use std::fs;
 use std::path::Path;
+use terminology::TermMatcher;
+
+/// How the checker reached its decision on a single word
+pub struct Explanation {
+    pub word: String,
+    /// Whether an ignore pattern matches the word
+    pub ignored: bool,
+    /// Each layer's verdict in lookup order, ending with the main dictionary
+    pub layers: Vec<(String, Option<Verdict>)>,
+    pub misspelled: bool,
+    /// Known word with the wrong capitalization (only with `case_sensitive`)
+    pub miscapitalized: bool,
+    /// Listed spellings with capitals, e.g. "GitHub"
+    pub canonical: Vec<String>,
+    /// Corrections if the word is a known misspelling
+    pub corrections: Vec<String>,
+}
+
+/// A finding together with the byte range it covers in the content
+struct Finding {
+    error: SpellError,
+    start: usize,
+    end: usize,
+}
 
 pub struct SpellChecker {
+    language: String,
+    case_sensitive: bool,
     dictionary: Dictionary,
-    personal_words: HashSet<String>,
+    layers: DictionaryStack,
+    misspellings: Misspellings,
+    terms: Option<TermMatcher>,
+    terms_severity: Severity,
+    keyboard_layout: KeyboardLayout,
+    phonetic: Option<Box<dyn PhoneticEncoder>>,
+    rules: Vec<ConfiguredRule>,
     ignore_patterns: Vec<Regex>,
+    changed_lines: Option<ChangedLines>,
+    baseline: Option<BaselineFilter>,
 }
 
 impl SpellChecker {
@@ -22,18 +72,26 @@ impl SpellChecker {
         // Load main dictionary
         let dictionary = Dictionary::load(&config.language)?;
 
-        // Load personal dictionary
-        let mut personal_words = HashSet::new();
+        // Layers are consulted in order: project words, configured lists, packs, personal words
+        let mut layers = DictionaryStack::default();
+        layers.push(Layer::from_words("config words", config.words.clone()));
+        for layer in &config.dictionaries {
+            layers.push(Layer::load(layer)?);
+        }
+        for pack in &config.packs {
+            layers.push(packs::load(pack)?);
+        }
+
         if let Some(personal_dict_path) = &config.personal_dictionary {
             if personal_dict_path.exists() {
                 let content = fs::read_to_string(personal_dict_path)
                     .context("Failed to read personal dictionary")?;
-                for line in content.lines() {
-                    let word = line.trim();
-                    if !word.is_empty() && !word.starts_with('#') {
-                        personal_words.insert(word.to_lowercase());
-                    }
-                }
+                let words = content
+                    .lines()
+                    .map(str::trim)
+                    .filter(|word| !word.is_empty() && !word.starts_with('#'))
+                    .map(String::from);
+                layers.push(Layer::from_words("personal", words));
             }
         }
 
@@ -47,12 +105,42 @@ impl SpellChecker {
         }
 
         Ok(Self {
+            language: config.language.clone(),
+            case_sensitive: config.case_sensitive,
             dictionary,
-            personal_words,
+            layers,
+            misspellings: Misspellings::new(&config.misspellings),
+            terms: TermMatcher::new(&config.terms)?,
+            terms_severity: config.terms_severity,
+            keyboard_layout: config.keyboard_layout,
+            phonetic: phonetic::encoder_for(&config.language),
+            rules: rules::enabled(config)?,
             ignore_patterns,
+            changed_lines: None,
+            baseline: None,
         })
     }
 
+    /// Only report and fix errors on lines that were added or modified
+    pub fn with_changed_lines(mut self, changed_lines: ChangedLines) -> Self {
+        self.changed_lines = Some(changed_lines);
+        self
+    }
+
+    /// Suppress findings recorded in a baseline
+    pub fn with_baseline(mut self, baseline: Baseline) -> Self {
+        self.baseline = Some(BaselineFilter::new(baseline));
+        self
+    }
+
+    /// Baseline entries of checked files that no longer match any finding
+    pub fn stale_baseline_entries(&self) -> Vec<BaselineEntry> {
+        self.baseline
+            .as_ref()
+            .map(|baseline| baseline.stale_entries())
+            .unwrap_or_default()
+    }
+
     pub fn check(
         &self,
         file_path: &Path,
@@ -63,113 +151,111 @@ impl SpellChecker {
         let content = fs::read_to_string(file_path)
             .with_context(|| format!("Failed to read file: {}", file_path.display()))?;
 
-        let spans = crate::parser::parse_file(file_path, &content)?;
-
-        let mut errors = Vec::new();
-
-        for span in spans {
-            let word_lower = span.text.to_lowercase();
-
-            // Skip if in personal dictionary
-            if self.personal_words.contains(&word_lower) {
-                continue;
-            }
-
-            // Skip if matches ignore pattern
-            if self.should_ignore(&span.text) {
-                continue;
-            }
-
-            // Skip if in main dictionary
-            if self.dictionary.contains(&word_lower) {
-                continue;
-            }
+        self.check_content(
+            file_path,
+            &content,
+            FileType::from_path(file_path),
+            config,
+            colored,
+            format,
+        )
+    }
 
-            // Word is misspelled - generate suggestions
-            let suggestions =
-                suggestions::generate(&word_lower, &self.dictionary, config.max_suggestions);
+    /// Check in-memory content, reporting errors under the given label
+    pub fn check_content(
+        &self,
+        label: &Path,
+        content: &str,
+        file_type: FileType,
+        config: &Config,
+        colored: bool,
+        format: &OutputFormat,
+    ) -> Result<CheckResult> {
+        let mut errors = self.find_errors(label, content, file_type, config)?;
 
-            errors.push(SpellError {
-                word: span.text.clone(),
-                line: span.line,
-                column: span.column,
-                context: span.original_text,
-                suggestions,
-            });
+        if let Some(baseline) = &self.baseline {
+            errors = baseline.suppress(label, content, errors);
         }
 
+        let error_count = errors
+            .iter()
+            .filter(|error| error.severity == Severity::Error)
+            .count();
         let result = CheckResult {
-            error_count: errors.len(),
+            error_count,
+            warning_count: errors.len() - error_count,
             fixed_count: 0,
             errors,
         };
 
         // Print errors in requested format
-        print_errors(file_path, &result, colored, format);
+        print_errors(label, &result, colored, format);
 
         Ok(result)
     }
 
+    /// Find misspellings in content without reporting them
+    pub fn find_errors(
+        &self,
+        label: &Path,
+        content: &str,
+        file_type: FileType,
+        config: &Config,
+    ) -> Result<Vec<SpellError>> {
+        let findings = self.findings(label, content, file_type, config.max_suggestions)?;
+        Ok(findings.into_iter().map(|finding| finding.error).collect())
+    }
+
+    /// Apply confident corrections and report the findings left for a human
     pub fn fix_auto(
         &self,
         file_path: &Path,
-        _config: &Config,
-        _colored: bool,
+        config: &Config,
+        colored: bool,
+        format: &OutputFormat,
     ) -> Result<CheckResult> {
         let content = fs::read_to_string(file_path)
             .with_context(|| format!("Failed to read file: {}", file_path.display()))?;
 
-        let spans = crate::parser::parse_file(file_path, &content)?;
+        let findings = self.findings(
+            file_path,
+            &content,
+            FileType::from_path(file_path),
+            config.max_suggestions,
+        )?;
+        let findings = self.new_findings(file_path, &content, findings);
         let mut replacements = Vec::new();
+        let mut unfixed = Vec::new();
 
-        for span in spans {
-            let word_lower = span.text.to_lowercase();
-
-            if self.personal_words.contains(&word_lower) {
-                continue;
-            }
-
-            if self.should_ignore(&span.text) {
-                continue;
-            }
-
-            if self.dictionary.contains(&word_lower) {
-                continue;
-            }
-
-            // Get top suggestion
-            let suggestions = suggestions::generate(&word_lower, &self.dictionary, 1);
-            if let Some(top_suggestion) = suggestions.first() {
-                // Store the start and end offsets and the replacement word
-                replacements.push((span.start, span.end, top_suggestion.clone()));
-            }
-        }
-
-        // Apply replacements at specific positions (from end to start)
-        let mut new_content = content.clone();
-        let mut fixed_count = 0;
-
-        // Sort by start offset in reverse order to avoid shifting positions
-        replacements.sort_by(|a, b| b.0.cmp(&a.0));
-        for (start, end, new_word) in &replacements {
-            // Defensive: check bounds
-            if *start < *end && *end <= new_content.len() {
-                new_content.replace_range(*start..*end, new_word);
-                fixed_count += 1;
+        for finding in findings {
+            // Store the start and end offsets and the top suggestion
+            match finding.error.suggestions.first() {
+                Some(top_suggestion) if finding.error.confidence >= config.fix_min_confidence => {
+                    replacements.push((finding.start, finding.end, top_suggestion.clone()));
+                }
+                _ => unfixed.push(finding.error),
             }
         }
 
-        // Write back to file
-        if fixed_count > 0 {
-            fs::write(file_path, new_content)
-                .with_context(|| format!("Failed to write file: {}", file_path.display()))?;
-        }
+        let fixed_count = write_replacements(file_path, &content, replacements)?;
 
-        Ok(CheckResult {
-            error_count: 0,
+        let result = CheckResult {
+            error_count: unfixed
+                .iter()
+                .filter(|error| error.severity == Severity::Error)
+                .count(),
+            warning_count: unfixed
+                .iter()
+                .filter(|error| error.severity != Severity::Error)
+                .count(),
             fixed_count,
-            errors: Vec::new(),
-        })
+            errors: unfixed,
+        };
+
+        // Ambiguous corrections are left as reported findings
+        print_errors(file_path, &result, colored, format);
+
+        Ok(result)
     }
 
     pub fn fix_interactive(
@@ -181,68 +267,42 @@ impl SpellChecker {
         let content = fs::read_to_string(file_path)
             .with_context(|| format!("Failed to read file: {}", file_path.display()))?;
 
-        let spans = crate::parser::parse_file(file_path, &content)?;
+        let findings = self.findings(
+            file_path,
+            &content,
+            FileType::from_path(file_path),
+            config.max_suggestions,
+        )?;
+        let findings = self.new_findings(file_path, &content, findings);
         let mut replacements = Vec::new();
         let mut words_to_add = Vec::new();
 
-        for span in spans {
-            let word_lower = span.text.to_lowercase();
-
-            if self.personal_words.contains(&word_lower) {
-                continue;
-            }
-
-            if self.should_ignore(&span.text) {
-                continue;
-            }
-
-            if self.dictionary.contains(&word_lower) {
-                continue;
-            }
-
-            // Get suggestions
-            let suggestions =
-                suggestions::generate(&word_lower, &self.dictionary, config.max_suggestions);
-
+        for Finding {
+            error, start, end, ..
+        } in findings
+        {
             // Prompt user
             if let Some(choice) = print_interactive_prompt(
-                &span.text,
-                &suggestions,
-                &span.original_text,
-                span.line,
-                span.column,
+                &error.word,
+                &error.suggestions,
+                &error.context,
+                error.line,
+                error.column,
                 colored,
             ) {
-                if choice == span.text {
-                    // User chose to add to dictionary
-                    words_to_add.push(word_lower);
+                if choice == error.word {
+                    // User chose to add to dictionary; forbidden terms and phrases stay out
+                    if error.kind == ErrorKind::Misspelling && !error.word.contains(' ') {
+                        words_to_add.push(error.word.to_lowercase());
+                    }
                 } else {
                     // User chose a replacement
-                    // Store (start, end, replacement)
-                    replacements.push((span.start, span.end, choice));
+                    replacements.push((start, end, choice));
                 }
             }
         }
 
-        // Apply replacements using byte offsets to avoid replacing wrong occurrences
-        let mut new_content = content.clone();
-        let mut fixed_count = 0;
-
-        // Sort replacements by start offset in descending order
-        replacements.sort_by(|a, b| b.0.cmp(&a.0));
-        for (start, end, new_word) in &replacements {
-            // Defensive: check bounds
-            if *start < *end && *end <= new_content.len() {
-                new_content.replace_range(*start..*end, new_word);
-                fixed_count += 1;
-            }
-        }
-
-        // Write back to file
-        if fixed_count > 0 {
-            fs::write(file_path, new_content)
-                .with_context(|| format!("Failed to write file: {}", file_path.display()))?;
-        }
+        let fixed_count = write_replacements(file_path, &content, replacements)?;
 
         // Add words to personal dictionary
         if !words_to_add.is_empty() {
@@ -262,12 +322,407 @@ impl SpellChecker {
         }
 
         Ok(CheckResult {
-            error_count: 0,
             fixed_count,
-            errors: Vec::new(),
+            ..Default::default()
         })
     }
 
+    /// Findings not recorded in the baseline, keeping their offsets for fixing
+    fn new_findings(&self, label: &Path, content: &str, findings: Vec<Finding>) -> Vec<Finding> {
+        match &self.baseline {
+            Some(baseline) => baseline.suppress_by(label, content, findings, |f| &f.error),
+            None => findings,
+        }
+    }
+
+    /// All findings in content, in document order
+    fn findings(
+        &self,
+        path: &Path,
+        content: &str,
+        file_type: FileType,
+        max_suggestions: usize,
+    ) -> Result<Vec<Finding>> {
+        let spans = self.join_compounds(crate::parser::parse_content(file_type, content)?);
+        let directives = directives::parse(content, file_type);
+        let changed = self.changed_lines.as_ref().map(|lines| lines.file(path));
+        let changed = changed.as_ref();
+
+        let mut findings = Vec::new();
+        for (span, is_piece) in &spans {
+            let Some(kind) = self.classify(changed, span, *is_piece, &directives) else {
+                continue;
+            };
+
+            let suggestions = self.suggest(&span.text, kind, max_suggestions);
+            findings.push(Finding {
+                error: SpellError {
+                    word: span.text.clone(),
+                    line: span.line,
+                    column: span.column,
+                    context: span.original_text.clone(),
+                    confidence: self.confidence(&span.text, kind, &suggestions),
+                    suggestions,
+                    kind,
+                    severity: Severity::Error,
+                },
+                start: span.start,
+                end: span.end,
+            });
+        }
+
+        // Rule findings take precedence over spelling findings within them
+        let words: Vec<TextSpan> = spans.into_iter().map(|(span, _)| span).collect();
+        let ruled = self.apply_rules(changed, content, file_type, &words, &directives);
+        retain_outside(&mut findings, &ruled);
+        findings.extend(ruled);
+
+        // A forbidden term takes precedence over spelling findings within it
+        let terms = self.find_terms(changed, content, &words, &directives);
+        retain_outside(&mut findings, &terms);
+        findings.extend(terms);
+        findings.sort_by_key(|finding| (finding.error.line, finding.error.column));
+
+        Ok(findings)
+    }
+
+    /// Findings of the enabled rules, in scope and not suppressed
+    fn apply_rules(
+        &self,
+        changed: Option<&FileChanges>,
+        content: &str,
+        file_type: FileType,
+        words: &[TextSpan],
+        directives: &Directives,
+    ) -> Vec<Finding> {
+        let is_known = |word: &str| self.is_known(word);
+        let in_layer = |word: &str| self.layers.lookup(word).is_some();
+        let context = RuleContext {
+            content,
+            file_type,
+            language: &self.language,
+            spans: words,
+            dictionary: &self.dictionary,
+            misspellings: &self.misspellings,
+            is_known: &is_known,
+            in_layer: &in_layer,
+        };
+
+        let mut findings = Vec::new();
+        for configured in &self.rules {
+            for found in configured.rule.check(&context) {
+                let (line, column, line_text) = locate(content, found.start);
+                let word = &content[found.start..found.end];
+                if !in_scope(changed, line)
+                    || directives.is_suppressed(line)
+                    || directives.allows_word(&word.to_lowercase())
+                {
+                    continue;
+                }
+
+                findings.push(Finding {
+                    error: SpellError {
+                        // Matches may span a line break
+                        word: word.split_whitespace().collect::<Vec<_>>().join(" "),
+                        line,
+                        column,
+                        context: line_text.to_string(),
+                        suggestions: found.suggestions,
+                        kind: ErrorKind::Rule(configured.rule.id()),
+                        severity: configured.severity,
+                        confidence: found.confidence,
+                    },
+                    start: found.start,
+                    end: found.end,
+                });
+            }
+        }
+
+        findings
+    }
+
+    /// Whether a lowercased word is accepted by a layer or the main dictionary
+    pub fn is_known(&self, word: &str) -> bool {
+        match self.layers.lookup(word) {
+            Some((_, verdict)) => verdict == Verdict::Accepted,
+            None => self.dictionary.contains(word),
+        }
+    }
+
+    /// Forbidden terms inside the checked text of a file
+    fn find_terms(
+        &self,
+        changed: Option<&FileChanges>,
+        content: &str,
+        spans: &[TextSpan],
+        directives: &Directives,
+    ) -> Vec<Finding> {
+        let Some(terms) = &self.terms else {
+            return Vec::new();
+        };
+
+        let mut findings = Vec::new();
+        for term in terms.find(content) {
+            let (line, column, line_text) = locate(content, term.start);
+            let word = &content[term.start..term.end];
+
+            // Only flag text the parser extracted, e.g. comments rather than code
+            let in_text = spans
+                .iter()
+                .any(|span| span.start < term.end && term.start < span.end);
+
+            if !in_text
+                || !in_scope(changed, line)
+                || directives.is_suppressed(line)
+                || directives.allows_word(&word.to_lowercase())
+            {
+                continue;
+            }
+
+            findings.push(Finding {
+                error: SpellError {
+                    word: word.to_string(),
+                    line,
+                    column,
+                    context: line_text.to_string(),
+                    suggestions: term
+                        .preferred
+                        .map(|preferred| suggestions::match_case(word, &preferred))
+                        .into_iter()
+                        .collect(),
+                    kind: ErrorKind::Terminology,
+                    severity: self.terms_severity,
+                    // Configured replacements are applied as-is
+                    confidence: 1.0,
+                },
+                start: term.start,
+                end: term.end,
+            });
+        }
+
+        findings
+    }
+
+    /// Rejoin adjacent compound-word pieces that form a listed word (e.g., "Git" + "Hub"),
+    /// marking the pieces that stay split
+    fn join_compounds(&self, spans: Vec<TextSpan>) -> Vec<(TextSpan, bool)> {
+        let mut runs: Vec<Vec<TextSpan>> = Vec::new();
+        for span in spans {
+            let contiguous = runs.last().and_then(|run| run.last()).is_some_and(|last| {
+                last.line == span.line && last.start < last.end && last.end == span.start
+            });
+            match runs.last_mut() {
+                Some(run) if contiguous => run.push(span),
+                _ => runs.push(vec![span]),
+            }
+        }
+
+        let mut joined = Vec::new();
+        for run in runs {
+            if run.len() == 1 {
+                joined.extend(run.into_iter().map(|span| (span, false)));
+                continue;
+            }
+
+            let text: String = run.iter().map(|span| span.text.as_str()).collect();
+            let lower = text.to_lowercase();
+            if self.layers.lookup(&lower).is_some() || !self.canonical_forms(&lower).is_empty() {
+                let first = &run[0];
+                let span = TextSpan {
+                    text,
+                    line: first.line,
+                    column: first.column,
+                    start: first.start,
+                    end: run[run.len() - 1].end,
+                    original_text: first.original_text.clone(),
+                };
+                joined.push((span, false));
+            } else {
+                joined.extend(run.into_iter().map(|span| (span, true)));
+            }
+        }
+
+        joined
+    }
+
+    /// Decide whether a span should be reported, and as what kind of finding
+    fn classify(
+        &self,
+        changed: Option<&FileChanges>,
+        span: &TextSpan,
+        is_piece: bool,
+        directives: &Directives,
+    ) -> Option<ErrorKind> {
+        // Skip lines outside the diff being checked or suppressed inline
+        if !in_scope(changed, span.line) || directives.is_suppressed(span.line) {
+            return None;
+        }
+
+        let word_lower = span.text.to_lowercase();
+
+        // Skip if declared in the file
+        if directives.allows_word(&word_lower) {
+            return None;
+        }
+
+        // The first dictionary layer with an opinion decides
+        let verdict = self.layers.lookup(&word_lower).map(|(_, verdict)| verdict);
+        if verdict == Some(Verdict::Rejected) {
+            return Some(ErrorKind::Misspelling);
+        }
+
+        // Skip if matches ignore pattern
+        if self.should_ignore(&span.text) {
+            return None;
+        }
+
+        // Known misspellings are flagged even if the dictionary has them
+        if verdict.is_none() && self.misspellings.corrections(&word_lower).is_some() {
+            return Some(ErrorKind::Misspelling);
+        }
+
+        // Casing inside identifiers like `myGithubToken` is not prose
+        if !is_piece && self.is_miscapitalized(&span.text) {
+            return Some(ErrorKind::Capitalization);
+        }
+
+        // Otherwise the main dictionary decides
+        if verdict.is_none() && !self.dictionary.contains(&word_lower) {
+            return Some(ErrorKind::Misspelling);
+        }
+
+        None
+    }
+
+    /// Spellings with capitals of a lowercased word. A layer that lists the word decides;
+    /// otherwise the main dictionary's casing applies.
+    fn canonical_forms(&self, word: &str) -> Vec<&str> {
+        let forms = self.layers.canonical_forms(word);
+        if !forms.is_empty() || self.layers.lookup(word).is_some() {
+            return forms;
+        }
+        self.dictionary
+            .canonical(word)
+            .iter()
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// With `case_sensitive`, words listed with capitals must match one of their listed forms
+    fn is_miscapitalized(&self, word: &str) -> bool {
+        if !self.case_sensitive {
+            return false;
+        }
+
+        let forms = self.canonical_forms(&word.to_lowercase());
+        !forms.is_empty()
+            && !forms
+                .iter()
+                .any(|form| *form == word || form.to_uppercase() == word)
+    }
+
+    fn suggest(&self, word: &str, kind: ErrorKind, max_suggestions: usize) -> Vec<String> {
+        match kind {
+            ErrorKind::Capitalization => self
+                .canonical_forms(&word.to_lowercase())
+                .into_iter()
+                .take(max_suggestions)
+                .map(String::from)
+                .collect(),
+            ErrorKind::Misspelling => {
+                let word_lower = word.to_lowercase();
+                let mut suggestions = self
+                    .misspellings
+                    .corrections(&word_lower)
+                    .map(<[String]>::to_vec)
+                    .unwrap_or_default();
+                for suggestion in suggestions::generate(
+                    &word_lower,
+                    &self.dictionary,
+                    self.keyboard_layout,
+                    self.phonetic.as_deref(),
+                    max_suggestions,
+                ) {
+                    if !suggestions.contains(&suggestion) {
+                        suggestions.push(suggestion);
+                    }
+                }
+                suggestions.truncate(max_suggestions);
+                suggestions
+                    .iter()
+                    .map(|suggestion| suggestions::match_case(word, suggestion))
+                    .collect()
+            }
+            // Preferred terms and rule fixes come with the finding
+            ErrorKind::Terminology | ErrorKind::Rule(_) => Vec::new(),
+        }
+    }
+
+    /// How sure we are that the first suggestion is right
+    fn confidence(&self, word: &str, kind: ErrorKind, suggestions: &[String]) -> f64 {
+        let word_lower = word.to_lowercase();
+        let unambiguous = |n: usize| if n == 1 { 1.0 } else { 0.5 };
+
+        match kind {
+            ErrorKind::Misspelling => match self.misspellings.corrections(&word_lower) {
+                Some(corrections) => unambiguous(corrections.len()),
+                None => {
+                    // Suggestions carry the word's casing; the dictionary is lowercase
+                    let lowered: Vec<String> =
+                        suggestions.iter().map(|s| s.to_lowercase()).collect();
+                    suggestions::confidence(&word_lower, &lowered, &self.dictionary)
+                }
+            },
+            ErrorKind::Capitalization => unambiguous(suggestions.len()),
+            ErrorKind::Terminology | ErrorKind::Rule(_) => 1.0,
+        }
+    }
+
+    /// Report how each dictionary layer judges a word
+    pub fn explain(&self, word: &str) -> Explanation {
+        let word_lower = word.to_lowercase();
+
+        let mut layers: Vec<(String, Option<Verdict>)> = self
+            .layers
+            .verdicts(&word_lower)
+            .into_iter()
+            .map(|(name, verdict)| (name.to_string(), verdict))
+            .collect();
+        layers.push((
+            format!("main ({})", self.language),
+            self.dictionary
+                .contains(&word_lower)
+                .then_some(Verdict::Accepted),
+        ));
+
+        let ignored = self.should_ignore(word);
+        let corrections = self
+            .misspellings
+            .corrections(&word_lower)
+            .map(<[String]>::to_vec)
+            .unwrap_or_default();
+        let misspelled = match self.layers.lookup(&word_lower) {
+            Some((_, verdict)) => verdict == Verdict::Rejected,
+            None => !ignored && (!corrections.is_empty() || !self.dictionary.contains(&word_lower)),
+        };
+        let miscapitalized = !misspelled && !ignored && self.is_miscapitalized(word);
+
+        Explanation {
+            word: word.to_string(),
+            ignored,
+            layers,
+            misspelled,
+            miscapitalized,
+            canonical: self
+                .layers
+                .canonical_forms(&word_lower)
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            corrections,
+        }
+    }
+
     fn should_ignore(&self, word: &str) -> bool {
         // Skip single characters
         if word.len() <= 1 {
@@ -289,3 +744,62 @@ impl SpellChecker {
         false
     }
 }
+
+/// Line number, column and trimmed text of the line containing a byte offset
+fn locate(content: &str, offset: usize) -> (usize, usize, &str) {
+    let line_start = content[..offset].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = content[offset..]
+        .find('\n')
+        .map_or(content.len(), |i| offset + i);
+    let line = content[..offset].matches('\n').count() + 1;
+    (
+        line,
+        offset - line_start + 1,
+        content[line_start..line_end].trim(),
+    )
+}
+
+/// Whether a line is checked: every line without a diff, otherwise only changed ones
+fn in_scope(changed: Option<&FileChanges>, line: usize) -> bool {
+    changed.is_none_or(|changed| changed.contains(line))
+}
+
+/// Drop findings that overlap any of the overriding ones
+fn retain_outside(findings: &mut Vec<Finding>, overriding: &[Finding]) {
+    findings.retain(|finding| {
+        !overriding
+            .iter()
+            .any(|other| finding.start < other.end && other.start < finding.end)
+    });
+}
+
+/// Apply (start, end, replacement) edits and write the file, returning how many were applied
+fn write_replacements(
+    file_path: &Path,
+    content: &str,
+    mut replacements: Vec<(usize, usize, String)>,
+) -> Result<usize> {
+    let mut new_content = content.to_string();
+    let mut fixed_count = 0;
+    // Start of the last applied edit; overlapping edits are skipped
+    let mut applied_from = usize::MAX;
+
+    // Sort by start offset in reverse order to avoid shifting positions
+    replacements.sort_by_key(|r| std::cmp::Reverse(r.0));
+    for (start, end, new_word) in &replacements {
+        // Defensive: check bounds
+        if *start < *end && *end <= new_content.len() && *end <= applied_from {
+            new_content.replace_range(*start..*end, new_word);
+            applied_from = *start;
+            fixed_count += 1;
+        }
+    }
+
+    // Write back to file
+    if fixed_count > 0 {
+        fs::write(file_path, new_content)
+            .with_context(|| format!("Failed to write file: {}", file_path.display()))?;
+    }
+
+    Ok(fixed_count)
+}
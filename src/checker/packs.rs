@@ -0,0 +1,66 @@
+use crate::checker::layers::Layer;
+use anyhow::Result;
+
+/// Built-in technical vocabulary packs, embedded with the `packs` feature
+#[cfg(feature = "packs")]
+const PACKS: &[(&str, &str)] = &[
+    ("software", include_str!("../../packs/software.txt")),
+    ("rust", include_str!("../../packs/rust.txt")),
+    ("javascript", include_str!("../../packs/javascript.txt")),
+    ("python", include_str!("../../packs/python.txt")),
+    ("devops", include_str!("../../packs/devops.txt")),
+    ("linux", include_str!("../../packs/linux.txt")),
+];
+
+#[cfg(not(feature = "packs"))]
+const PACKS: &[(&str, &str)] = &[];
+
+/// Names of the packs compiled into this binary
+pub fn available() -> Vec<&'static str> {
+    PACKS.iter().map(|(name, _)| *name).collect()
+}
+
+/// Load a bundled pack as a dictionary layer
+pub fn load(name: &str) -> Result<Layer> {
+    let Some((_, content)) = PACKS.iter().find(|(pack, _)| *pack == name) else {
+        if PACKS.is_empty() {
+            anyhow::bail!(
+                "Word pack '{}' requested, but spellchk was built without the `packs` feature",
+                name
+            );
+        }
+        anyhow::bail!(
+            "Unknown word pack '{}' (available: {})",
+            name,
+            available().join(", ")
+        );
+    };
+
+    let words = content
+        .lines()
+        .map(str::trim)
+        .filter(|word| !word.is_empty() && !word.starts_with('#'))
+        .map(String::from);
+
+    Ok(Layer::from_words(format!("pack:{}", name), words))
+}
+
+#[cfg(all(test, feature = "packs"))]
+mod tests {
+    use super::*;
+    use crate::checker::layers::Verdict;
+
+    #[test]
+    fn test_load_pack() {
+        let layer = load("devops").unwrap();
+        assert_eq!(layer.name, "pack:devops");
+        assert_eq!(layer.verdict("kubernetes"), Some(Verdict::Accepted));
+        assert_eq!(layer.verdict("# cloud and devops vocabulary"), None);
+    }
+
+    #[test]
+    fn test_unknown_pack() {
+        let err = load("cobol").err().unwrap().to_string();
+        assert!(err.contains("available: software, rust"));
+    }
+}
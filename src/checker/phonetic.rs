@@ -0,0 +1,697 @@
+//! Phonetic encodings for sound-alike suggestions ("fonetik" → "phonetic")
+
+/// Encodes a word into the codes of words that sound like it
+pub trait PhoneticEncoder: Send + Sync {
+    /// Phonetic codes for a word, primary first; empty if the word can't be encoded
+    fn encode(&self, word: &str) -> Vec<String>;
+}
+
+/// Phonetic encoder for a dictionary language, if one exists
+pub fn encoder_for(language: &str) -> Option<Box<dyn PhoneticEncoder>> {
+    match language.split(['_', '-']).next() {
+        Some("en") => Some(Box::new(DoubleMetaphone::default())),
+        _ => None,
+    }
+}
+
+/// Lawrence Philips' Double Metaphone, tuned for English and common loanwords
+pub struct DoubleMetaphone {
+    max_length: usize,
+}
+
+impl Default for DoubleMetaphone {
+    fn default() -> Self {
+        Self { max_length: 4 }
+    }
+}
+
+impl PhoneticEncoder for DoubleMetaphone {
+    fn encode(&self, word: &str) -> Vec<String> {
+        let value: Vec<char> = word.trim().chars().flat_map(char::to_uppercase).collect();
+        if value.is_empty() {
+            return Vec::new();
+        }
+
+        let mut encoder = Encoder {
+            slavo_germanic: is_slavo_germanic(&value),
+            value,
+            primary: String::new(),
+            alternate: String::new(),
+            max_length: self.max_length,
+        };
+        encoder.run();
+
+        let Encoder {
+            primary, alternate, ..
+        } = encoder;
+        match (primary.is_empty(), alternate == primary) {
+            (true, _) => Vec::new(),
+            (false, true) => vec![primary],
+            (false, false) => vec![primary, alternate],
+        }
+    }
+}
+
+const L_R_N_M_B_H_F_V_W_SPACE: &[&str] = &["L", "R", "N", "M", "B", "H", "F", "V", "W", " "];
+const ES_EP_EB_EL_EY_IB_IL_IN_IE_EI_ER: &[&str] = &[
+    "ES", "EP", "EB", "EL", "EY", "IB", "IL", "IN", "IE", "EI", "ER",
+];
+const L_T_K_S_N_M_B_Z: &[&str] = &["L", "T", "K", "S", "N", "M", "B", "Z"];
+
+fn is_slavo_germanic(value: &[char]) -> bool {
+    let value: String = value.iter().collect();
+    value.contains('W') || value.contains('K') || value.contains("CZ") || value.contains("WITZ")
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'A' | 'E' | 'I' | 'O' | 'U' | 'Y')
+}
+
+/// State of one encoding run
+struct Encoder {
+    value: Vec<char>,
+    slavo_germanic: bool,
+    primary: String,
+    alternate: String,
+    max_length: usize,
+}
+
+impl Encoder {
+    fn run(&mut self) {
+        let mut index = 0;
+        if self.contains(0, 2, &["GN", "KN", "PN", "WR", "PS"]) {
+            index = 1;
+        }
+        if self.at(0) == 'X' {
+            // Initial "X" is pronounced "Z", which maps to "S"
+            self.add("S");
+            index = 1;
+        }
+
+        while !self.is_complete() && index < self.value.len() {
+            index = match self.value[index] {
+                'A' | 'E' | 'I' | 'O' | 'U' | 'Y' => {
+                    // Vowels only count at the start of a word
+                    if index == 0 {
+                        self.add("A");
+                    }
+                    index + 1
+                }
+                'B' => {
+                    self.add("P");
+                    self.skip_double(index, 'B')
+                }
+                'Ç' => {
+                    self.add("S");
+                    index + 1
+                }
+                'C' => self.handle_c(index),
+                'D' => self.handle_d(index),
+                'F' => {
+                    self.add("F");
+                    self.skip_double(index, 'F')
+                }
+                'G' => self.handle_g(index),
+                'H' => self.handle_h(index),
+                'J' => self.handle_j(index),
+                'K' => {
+                    self.add("K");
+                    self.skip_double(index, 'K')
+                }
+                'L' => self.handle_l(index),
+                'M' => {
+                    self.add("M");
+                    if self.condition_m0(index) {
+                        index + 2
+                    } else {
+                        index + 1
+                    }
+                }
+                'N' => {
+                    self.add("N");
+                    self.skip_double(index, 'N')
+                }
+                'Ñ' => {
+                    self.add("N");
+                    index + 1
+                }
+                'P' => self.handle_p(index),
+                'Q' => {
+                    self.add("K");
+                    self.skip_double(index, 'Q')
+                }
+                'R' => self.handle_r(index),
+                'S' => self.handle_s(index),
+                'T' => self.handle_t(index),
+                'V' => {
+                    self.add("F");
+                    self.skip_double(index, 'V')
+                }
+                'W' => self.handle_w(index),
+                'X' => self.handle_x(index),
+                'Z' => self.handle_z(index),
+                _ => index + 1,
+            };
+        }
+
+        self.primary.truncate(self.max_length);
+        self.alternate.truncate(self.max_length);
+    }
+
+    fn handle_c(&mut self, index: usize) -> usize {
+        if self.condition_c0(index) {
+            // Germanic "ACH", e.g. "bacher"
+            self.add("K");
+            index + 2
+        } else if index == 0 && self.contains(index, 6, &["CAESAR"]) {
+            self.add("S");
+            index + 2
+        } else if self.contains(index, 2, &["CH"]) {
+            self.handle_ch(index)
+        } else if self.contains(index, 2, &["CZ"]) && !self.contains_at(index, -2, 4, &["WICZ"]) {
+            self.add_both("S", "X");
+            index + 2
+        } else if self.contains(index + 1, 3, &["CIA"]) {
+            self.add("X");
+            index + 3
+        } else if self.contains(index, 2, &["CC"]) && !(index == 1 && self.at(0) == 'M') {
+            self.handle_cc(index)
+        } else if self.contains(index, 2, &["CK", "CG", "CQ"]) {
+            self.add("K");
+            index + 2
+        } else if self.contains(index, 2, &["CI", "CE", "CY"]) {
+            if self.contains(index, 3, &["CIO", "CIE", "CIA"]) {
+                self.add_both("S", "X");
+            } else {
+                self.add("S");
+            }
+            index + 2
+        } else {
+            self.add("K");
+            if self.contains(index + 1, 2, &[" C", " Q", " G"]) {
+                index + 3
+            } else if self.contains(index + 1, 1, &["C", "K", "Q"])
+                && !self.contains(index + 1, 2, &["CE", "CI"])
+            {
+                index + 2
+            } else {
+                index + 1
+            }
+        }
+    }
+
+    fn handle_ch(&mut self, index: usize) -> usize {
+        if index > 0 && self.contains(index, 4, &["CHAE"]) {
+            self.add_both("K", "X");
+        } else if self.condition_ch0(index) || self.condition_ch1(index) {
+            // Greek roots ("chorus") and Germanic words ("orchestra")
+            self.add("K");
+        } else if index > 0 {
+            if self.contains(0, 2, &["MC"]) {
+                self.add("K");
+            } else {
+                self.add_both("X", "K");
+            }
+        } else {
+            self.add("X");
+        }
+        index + 2
+    }
+
+    fn handle_cc(&mut self, index: usize) -> usize {
+        if self.contains(index + 2, 1, &["I", "E", "H"]) && !self.contains(index + 2, 2, &["HU"]) {
+            // "accident", "accede", "succeed"
+            if (index == 1 && self.at(0) == 'A')
+                || self.contains_at(index, -1, 5, &["UCCEE", "UCCES"])
+            {
+                self.add("KS");
+            } else {
+                // "bacci", "bertucci"
+                self.add("X");
+            }
+            index + 3
+        } else {
+            self.add("K");
+            index + 2
+        }
+    }
+
+    fn handle_d(&mut self, index: usize) -> usize {
+        if self.contains(index, 2, &["DG"]) {
+            if self.contains(index + 2, 1, &["I", "E", "Y"]) {
+                // "edge"
+                self.add("J");
+                index + 3
+            } else {
+                // "edgar"
+                self.add("TK");
+                index + 2
+            }
+        } else if self.contains(index, 2, &["DT", "DD"]) {
+            self.add("T");
+            index + 2
+        } else {
+            self.add("T");
+            index + 1
+        }
+    }
+
+    fn handle_g(&mut self, index: usize) -> usize {
+        let next = self.at(index + 1);
+        if next == 'H' {
+            self.handle_gh(index)
+        } else if next == 'N' {
+            if index == 1 && is_vowel(self.at(0)) && !self.slavo_germanic {
+                self.add_both("KN", "N");
+            } else if !self.contains(index + 2, 2, &["EY"])
+                && self.at(index + 1) != 'Y'
+                && !self.slavo_germanic
+            {
+                self.add_both("N", "KN");
+            } else {
+                self.add("KN");
+            }
+            index + 2
+        } else if self.contains(index + 1, 2, &["LI"]) && !self.slavo_germanic {
+            self.add_both("KL", "L");
+            index + 2
+        } else if self.condition_g0(index) {
+            // Hard or soft, e.g. "gerald", "gym", "tiger"
+            self.add_both("K", "J");
+            index + 2
+        } else if self.contains(index + 1, 1, &["E", "I", "Y"])
+            || self.contains_at(index, -1, 4, &["AGGI", "OGGI"])
+        {
+            if self.contains(0, 4, &["VAN ", "VON "])
+                || self.contains(0, 3, &["SCH"])
+                || self.contains(index + 1, 2, &["ET"])
+            {
+                self.add("K");
+            } else if self.contains(index + 1, 3, &["IER"]) {
+                self.add("J");
+            } else {
+                self.add_both("J", "K");
+            }
+            index + 2
+        } else {
+            self.add("K");
+            self.skip_double(index, 'G')
+        }
+    }
+
+    fn handle_gh(&mut self, index: usize) -> usize {
+        if index > 0 && !is_vowel(self.at(index - 1)) {
+            self.add("K");
+        } else if index == 0 {
+            if self.at(index + 2) == 'I' {
+                self.add("J");
+            } else {
+                self.add("K");
+            }
+        } else if (index > 1 && self.contains(index - 2, 1, &["B", "H", "D"]))
+            || (index > 2 && self.contains(index - 3, 1, &["B", "H", "D"]))
+            || (index > 3 && self.contains(index - 4, 1, &["B", "H"]))
+        {
+            // Silent, e.g. "hugh", "bough", "broughton"
+        } else if index > 2
+            && self.at(index - 1) == 'U'
+            && self.contains(index - 3, 1, &["C", "G", "L", "R", "T"])
+        {
+            // "laugh", "cough", "tough"
+            self.add("F");
+        } else if self.at(index - 1) != 'I' {
+            self.add("K");
+        }
+        index + 2
+    }
+
+    fn handle_h(&mut self, index: usize) -> usize {
+        // Only keep an "H" between vowels or at the start before a vowel
+        if (index == 0 || is_vowel(self.at(index - 1))) && is_vowel(self.at(index + 1)) {
+            self.add("H");
+            index + 2
+        } else {
+            index + 1
+        }
+    }
+
+    fn handle_j(&mut self, index: usize) -> usize {
+        if self.contains(index, 4, &["JOSE"]) || self.contains(0, 4, &["SAN "]) {
+            if (index == 0 && self.at(index + 4) == ' ')
+                || self.value.len() == 4
+                || self.contains(0, 4, &["SAN "])
+            {
+                self.add("H");
+            } else {
+                self.add_both("J", "H");
+            }
+            return index + 1;
+        }
+
+        if index == 0 {
+            self.add_both("J", "A");
+        } else if is_vowel(self.at(index - 1))
+            && !self.slavo_germanic
+            && matches!(self.at(index + 1), 'A' | 'O')
+        {
+            self.add_both("J", "H");
+        } else if index == self.value.len() - 1 {
+            self.add_both("J", "");
+        } else if !self.contains(index + 1, 1, L_T_K_S_N_M_B_Z)
+            && !self.contains_at(index, -1, 1, &["S", "K", "L"])
+        {
+            self.add("J");
+        }
+        self.skip_double(index, 'J')
+    }
+
+    fn handle_l(&mut self, index: usize) -> usize {
+        if self.at(index + 1) == 'L' {
+            if self.condition_l0(index) {
+                // Spanish "-illo", "-illa"
+                self.add_both("L", "");
+            } else {
+                self.add("L");
+            }
+            index + 2
+        } else {
+            self.add("L");
+            index + 1
+        }
+    }
+
+    fn handle_p(&mut self, index: usize) -> usize {
+        if self.at(index + 1) == 'H' {
+            self.add("F");
+            index + 2
+        } else {
+            self.add("P");
+            if self.contains(index + 1, 1, &["P", "B"]) {
+                index + 2
+            } else {
+                index + 1
+            }
+        }
+    }
+
+    fn handle_r(&mut self, index: usize) -> usize {
+        // French endings like "rogier" drop the final "R" in the primary code
+        if index == self.value.len() - 1
+            && !self.slavo_germanic
+            && self.contains_at(index, -2, 2, &["IE"])
+            && !self.contains_at(index, -4, 2, &["ME", "MA"])
+        {
+            self.add_both("", "R");
+        } else {
+            self.add("R");
+        }
+        self.skip_double(index, 'R')
+    }
+
+    fn handle_s(&mut self, index: usize) -> usize {
+        if self.contains_at(index, -1, 3, &["ISL", "YSL"]) {
+            // Silent, e.g. "island", "carlysle"
+            index + 1
+        } else if index == 0 && self.contains(index, 5, &["SUGAR"]) {
+            self.add_both("X", "S");
+            index + 1
+        } else if self.contains(index, 2, &["SH"]) {
+            if self.contains(index + 1, 4, &["HEIM", "HOEK", "HOLM", "HOLZ"]) {
+                self.add("S");
+            } else {
+                self.add("X");
+            }
+            index + 2
+        } else if self.contains(index, 3, &["SIO", "SIA"]) || self.contains(index, 4, &["SIAN"]) {
+            if self.slavo_germanic {
+                self.add("S");
+            } else {
+                self.add_both("S", "X");
+            }
+            index + 3
+        } else if (index == 0 && self.contains(index + 1, 1, &["M", "N", "L", "W"]))
+            || self.contains(index + 1, 1, &["Z"])
+        {
+            self.add_both("S", "X");
+            if self.contains(index + 1, 1, &["Z"]) {
+                index + 2
+            } else {
+                index + 1
+            }
+        } else if self.contains(index, 2, &["SC"]) {
+            self.handle_sc(index)
+        } else {
+            // French endings like "resnais" drop the final "S" in the primary code
+            if index == self.value.len() - 1 && self.contains_at(index, -2, 2, &["AI", "OI"]) {
+                self.add_both("", "S");
+            } else {
+                self.add("S");
+            }
+            if self.contains(index + 1, 1, &["S", "Z"]) {
+                index + 2
+            } else {
+                index + 1
+            }
+        }
+    }
+
+    fn handle_sc(&mut self, index: usize) -> usize {
+        if self.at(index + 2) == 'H' {
+            if self.contains(index + 3, 2, &["OO", "ER", "EN", "UY", "ED", "EM"]) {
+                // Dutch origin, e.g. "school", "schooner"
+                if self.contains(index + 3, 2, &["ER", "EN"]) {
+                    self.add_both("X", "SK");
+                } else {
+                    self.add("SK");
+                }
+            } else if index == 0 && !is_vowel(self.at(3)) && self.at(3) != 'W' {
+                self.add_both("X", "S");
+            } else {
+                self.add("X");
+            }
+        } else if self.contains(index + 2, 1, &["I", "E", "Y"]) {
+            self.add("S");
+        } else {
+            self.add("SK");
+        }
+        index + 3
+    }
+
+    fn handle_t(&mut self, index: usize) -> usize {
+        if self.contains(index, 4, &["TION"]) || self.contains(index, 3, &["TIA", "TCH"]) {
+            self.add("X");
+            index + 3
+        } else if self.contains(index, 2, &["TH"]) || self.contains(index, 3, &["TTH"]) {
+            if self.contains(index + 2, 2, &["OM", "AM"])
+                || self.contains(0, 4, &["VAN ", "VON "])
+                || self.contains(0, 3, &["SCH"])
+            {
+                self.add("T");
+            } else {
+                // "0" stands for "th"
+                self.add_both("0", "T");
+            }
+            index + 2
+        } else {
+            self.add("T");
+            if self.contains(index + 1, 1, &["T", "D"]) {
+                index + 2
+            } else {
+                index + 1
+            }
+        }
+    }
+
+    fn handle_w(&mut self, index: usize) -> usize {
+        if self.contains(index, 2, &["WR"]) {
+            self.add("R");
+            return index + 2;
+        }
+
+        if index == 0 && (is_vowel(self.at(index + 1)) || self.contains(index, 2, &["WH"])) {
+            if is_vowel(self.at(index + 1)) {
+                self.add_both("A", "F");
+            } else {
+                self.add("A");
+            }
+            index + 1
+        } else if (index == self.value.len() - 1 && index > 0 && is_vowel(self.at(index - 1)))
+            || self.contains_at(index, -1, 5, &["EWSKI", "EWSKY", "OWSKI", "OWSKY"])
+            || self.contains(0, 3, &["SCH"])
+        {
+            // Polish names like "filipowicz"
+            self.add_both("", "F");
+            index + 1
+        } else if self.contains(index, 4, &["WICZ", "WITZ"]) {
+            self.add_both("TS", "FX");
+            index + 4
+        } else {
+            index + 1
+        }
+    }
+
+    fn handle_x(&mut self, index: usize) -> usize {
+        if index == 0 {
+            self.add("S");
+            return index + 1;
+        }
+
+        // French endings like "breaux" are silent
+        let silent = index == self.value.len() - 1
+            && (self.contains_at(index, -3, 3, &["IAU", "EAU"])
+                || self.contains_at(index, -2, 2, &["AU", "OU"]));
+        if !silent {
+            self.add("KS");
+        }
+        if self.contains(index + 1, 1, &["C", "X"]) {
+            index + 2
+        } else {
+            index + 1
+        }
+    }
+
+    fn handle_z(&mut self, index: usize) -> usize {
+        if self.at(index + 1) == 'H' {
+            // Chinese pinyin, e.g. "zhao"
+            self.add("J");
+            return index + 2;
+        }
+
+        if self.contains(index + 1, 2, &["ZO", "ZI", "ZA"])
+            || (self.slavo_germanic && index > 0 && self.at(index - 1) != 'T')
+        {
+            self.add_both("S", "TS");
+        } else {
+            self.add("S");
+        }
+        self.skip_double(index, 'Z')
+    }
+
+    fn condition_c0(&self, index: usize) -> bool {
+        if self.contains(index, 4, &["CHIA"]) {
+            return true;
+        }
+        if index <= 1 || is_vowel(self.at(index - 2)) || !self.contains_at(index, -1, 3, &["ACH"]) {
+            return false;
+        }
+        let c = self.at(index + 2);
+        (c != 'I' && c != 'E') || self.contains_at(index, -2, 6, &["BACHER", "MACHER"])
+    }
+
+    fn condition_g0(&self, index: usize) -> bool {
+        let next = self.at(index + 1);
+        if index == 0 {
+            return next == 'Y' || self.contains(index + 1, 2, ES_EP_EB_EL_EY_IB_IL_IN_IE_EI_ER);
+        }
+        (self.contains(index + 1, 2, &["ER"]) || next == 'Y')
+            && !self.contains(0, 6, &["DANGER", "RANGER", "MANGER"])
+            && !self.contains_at(index, -1, 1, &["E", "I"])
+            && !self.contains_at(index, -1, 3, &["RGY", "OGY"])
+    }
+
+    fn condition_ch0(&self, index: usize) -> bool {
+        index == 0
+            && (self.contains(index + 1, 5, &["HARAC", "HARIS"])
+                || self.contains(index + 1, 3, &["HOR", "HYM", "HIA", "HEM"]))
+            && !self.contains(0, 5, &["CHORE"])
+    }
+
+    fn condition_ch1(&self, index: usize) -> bool {
+        self.contains(0, 4, &["VAN ", "VON "])
+            || self.contains(0, 3, &["SCH"])
+            || self.contains_at(index, -2, 6, &["ORCHES", "ARCHIT", "ORCHID"])
+            || self.contains(index + 2, 1, &["T", "S"])
+            || ((index == 0 || self.contains_at(index, -1, 1, &["A", "O", "U", "E"]))
+                && (self.contains(index + 2, 1, L_R_N_M_B_H_F_V_W_SPACE)
+                    || index + 1 == self.value.len() - 1))
+    }
+
+    fn condition_l0(&self, index: usize) -> bool {
+        let len = self.value.len();
+        if index + 3 == len && self.contains_at(index, -1, 4, &["ILLO", "ILLA", "ALLE"]) {
+            return true;
+        }
+        (self.contains(len.saturating_sub(2), 2, &["AS", "OS"])
+            || self.contains(len - 1, 1, &["A", "O"]))
+            && self.contains_at(index, -1, 4, &["ALLE"])
+    }
+
+    fn condition_m0(&self, index: usize) -> bool {
+        if self.at(index + 1) == 'M' {
+            return true;
+        }
+        // "dumb", "thumb", "dumber"
+        self.contains_at(index, -1, 3, &["UMB"])
+            && (index + 1 == self.value.len() - 1 || self.contains(index + 2, 2, &["ER"]))
+    }
+
+    fn at(&self, index: usize) -> char {
+        self.value.get(index).copied().unwrap_or('\0')
+    }
+
+    fn skip_double(&self, index: usize, letter: char) -> usize {
+        if self.at(index + 1) == letter {
+            index + 2
+        } else {
+            index + 1
+        }
+    }
+
+    /// Whether the `len` characters starting at `start` equal one of `options`
+    fn contains(&self, start: usize, len: usize, options: &[&str]) -> bool {
+        let Some(slice) = self.value.get(start..start + len) else {
+            return false;
+        };
+        let slice: String = slice.iter().collect();
+        options.contains(&slice.as_str())
+    }
+
+    /// Like `contains`, with the start given relative to `index` (possibly before it)
+    fn contains_at(&self, index: usize, offset: isize, len: usize, options: &[&str]) -> bool {
+        index
+            .checked_add_signed(offset)
+            .is_some_and(|start| self.contains(start, len, options))
+    }
+
+    fn add(&mut self, code: &str) {
+        self.add_both(code, code);
+    }
+
+    fn add_both(&mut self, primary: &str, alternate: &str) {
+        self.primary.push_str(primary);
+        self.alternate.push_str(alternate);
+    }
+
+    fn is_complete(&self) -> bool {
+        self.primary.len() >= self.max_length && self.alternate.len() >= self.max_length
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(word: &str) -> Vec<String> {
+        DoubleMetaphone::default().encode(word)
+    }
+
+    #[test]
+    fn test_double_metaphone() {
+        assert_eq!(encode("phonetic"), vec!["FNTK"]);
+        assert_eq!(encode("fonetik"), vec!["FNTK"]);
+        assert_eq!(encode("knowledge"), encode("nolige")[..1]);
+        assert_eq!(encode("Thomas"), vec!["TMS"]);
+        assert_eq!(encode("Smith"), vec!["SM0", "XMT"]);
+        assert_eq!(encode("school"), vec!["SKL"]);
+        assert_eq!(encode("laugh"), vec!["LF"]);
+        assert_eq!(encode("night"), encode("nite"));
+        assert!(encode("").is_empty());
+    }
+
+    #[test]
+    fn test_encoder_per_language() {
+        assert!(encoder_for("en_US").is_some());
+        assert!(encoder_for("en-GB").is_some());
+        assert!(encoder_for("de_DE").is_none());
+    }
+}
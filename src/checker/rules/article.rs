@@ -0,0 +1,238 @@
+use super::{parse_options, Rule, RuleContext, RuleMatch};
+use crate::checker::suggestions;
+use crate::parser::TextSpan;
+use crate::Severity;
+use anyhow::Result;
+use serde::Deserialize;
+
+/// Starts of words spelled with a vowel but said with a consonant ("a university")
+const CONSONANT_SOUND_PREFIXES: &[&str] = &[
+    "eu", "ewe", "once", "one", "ubiq", "unan", "uni", "ura", "ure", "uri", "uro", "usa", "use",
+    "usu", "uten", "uti", "uto",
+];
+
+/// "un-" words among the consonant-sound prefixes that are said with a vowel ("an uninstall")
+const VOWEL_SOUND_UN_PREFIXES: &[&str] = &["unid", "unim", "unin"];
+
+/// Starts of words spelled with a consonant but said with a vowel ("an hour")
+const VOWEL_SOUND_PREFIXES: &[&str] = &["heir", "honest", "honor", "honour", "hour"];
+
+/// Letters whose names start with a vowel sound ("an SQL", "an HTML")
+const VOWEL_SOUND_LETTERS: &str = "aefhilmnorsx";
+
+/// Acronyms said as words rather than letter by letter ("a NASA")
+const SPOKEN_ACRONYMS: &[&str] = &[
+    "fifo", "lan", "lifo", "mime", "nasa", "nat", "nato", "nic", "ram", "rest", "rom", "scsi",
+    "sim", "soap",
+];
+
+/// Words that can't follow an article, so "a" before them is a letter ("option a or b")
+const NOT_NOUNS: &[&str] = &[
+    "a", "also", "am", "an", "and", "are", "as", "at", "if", "in", "into", "is", "it", "its", "of",
+    "on", "onto", "or", "up", "us",
+];
+
+/// "a" before a vowel sound or "an" before a consonant sound, e.g. "a apple", "an user"
+pub struct ArticleAgreement {
+    /// Extra words that take "a"
+    a: Vec<String>,
+    /// Extra words that take "an"
+    an: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Options {
+    #[serde(default)]
+    a: Vec<String>,
+    #[serde(default)]
+    an: Vec<String>,
+}
+
+impl Default for ArticleAgreement {
+    fn default() -> Self {
+        Self {
+            a: SPOKEN_ACRONYMS
+                .iter()
+                .map(|word| word.to_string())
+                .collect(),
+            an: Vec::new(),
+        }
+    }
+}
+
+impl Rule for ArticleAgreement {
+    fn id(&self) -> &'static str {
+        "article-agreement"
+    }
+
+    fn description(&self) -> &'static str {
+        "\"a\" or \"an\" that doesn't match the next word's sound"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn configure(&mut self, options: &toml::Table) -> Result<()> {
+        let options: Options = parse_options(options)?;
+        let lowercase = |words: Vec<String>| words.into_iter().map(|word| word.to_lowercase());
+        self.a.extend(lowercase(options.a));
+        self.an.extend(lowercase(options.an));
+        Ok(())
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<RuleMatch> {
+        context
+            .spans
+            .iter()
+            .filter_map(|word| self.agree(context.content, word))
+            .collect()
+    }
+}
+
+impl ArticleAgreement {
+    /// A match replacing the article before a word if the two disagree
+    fn agree(&self, content: &str, word: &TextSpan) -> Option<RuleMatch> {
+        if word.start >= word.end {
+            return None;
+        }
+
+        // Parsers drop one-letter words, so the article is read from the content
+        let before = &content[..word.start];
+        let preceding = before.trim_end();
+        let between = &before[preceding.len()..];
+        if between.is_empty() || between.matches('\n').count() > 1 {
+            return None;
+        }
+        let article_start = preceding
+            .char_indices()
+            .rev()
+            .take_while(|(_, c)| c.is_alphanumeric())
+            .last()
+            .map(|(i, _)| i)?;
+        let article = &preceding[article_start..];
+
+        let has_an = match article {
+            "a" | "A" => false,
+            "an" | "An" | "AN" => true,
+            _ => return None,
+        };
+
+        // A capital "A" mid-sentence is a name or a letter ("Plan A is")
+        let previous = preceding[..article_start].trim_end().chars().next_back();
+        if article == "A" && previous.is_some_and(char::is_alphanumeric) {
+            return None;
+        }
+        if NOT_NOUNS.contains(&word.text.to_lowercase().as_str()) {
+            return None;
+        }
+
+        let vowel_sound = self.vowel_sound(&word.text)?;
+        if vowel_sound == has_an {
+            return None;
+        }
+
+        let correct = if vowel_sound { "an" } else { "a" };
+        Some(RuleMatch {
+            start: article_start,
+            end: word.end,
+            suggestions: vec![format!(
+                "{}{}{}",
+                suggestions::match_case(article, correct),
+                between,
+                word.text
+            )],
+            // Pronunciation is guessed from spelling
+            confidence: 0.9,
+        })
+    }
+
+    /// Whether a word is said starting with a vowel sound, if it starts with a letter
+    fn vowel_sound(&self, word: &str) -> Option<bool> {
+        let lower = word.to_lowercase();
+        let first = lower.chars().next().filter(|c| c.is_alphabetic())?;
+
+        if self.a.contains(&lower) {
+            return Some(false);
+        }
+        if self.an.contains(&lower) {
+            return Some(true);
+        }
+
+        // Acronyms and single letters are read letter by letter ("an SQL", "an x-axis")
+        let letters = word.split('-').next().unwrap_or(word);
+        let acronym = letters
+            .chars()
+            .all(|c| c.is_alphabetic() && c.is_uppercase());
+        if acronym || letters.chars().count() == 1 {
+            return Some(VOWEL_SOUND_LETTERS.contains(first));
+        }
+
+        let starts_with = |prefixes: &[&str]| prefixes.iter().any(|p| lower.starts_with(p));
+        if starts_with(CONSONANT_SOUND_PREFIXES) && !starts_with(VOWEL_SOUND_UN_PREFIXES) {
+            return Some(false);
+        }
+        if starts_with(VOWEL_SOUND_PREFIXES) {
+            return Some(true);
+        }
+
+        Some("aeiou".contains(first))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checker::rules::tests::check;
+    use crate::parser::FileType;
+
+    fn disagreements(rule: &ArticleAgreement, content: &str) -> Vec<(String, Vec<String>)> {
+        check(rule, FileType::PlainText, content, &[])
+    }
+
+    #[test]
+    fn test_article_agreement() {
+        let rule = ArticleAgreement::default();
+        assert_eq!(
+            disagreements(&rule, "A apple, an user and a hour."),
+            vec![
+                ("A apple".to_string(), vec!["An apple".to_string()]),
+                ("an user".to_string(), vec!["a user".to_string()]),
+                ("a hour".to_string(), vec!["an hour".to_string()]),
+            ]
+        );
+
+        // Exceptions to the spelling
+        assert!(disagreements(
+            &rule,
+            "an hour, an honest, a university, an uninstaller, a one-off, a European"
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_acronyms_and_letters() {
+        let rule = ArticleAgreement::default();
+        assert!(disagreements(&rule, "an SQL query, a URL, an HTML page, a NASA probe").is_empty());
+        assert_eq!(
+            disagreements(&rule, "a SQL query and an URL"),
+            vec![
+                ("a SQL".to_string(), vec!["an SQL".to_string()]),
+                ("an URL".to_string(), vec!["a URL".to_string()]),
+            ]
+        );
+
+        // "A" as a name or letter rather than an article
+        assert!(disagreements(&rule, "Plan A is ready; pick a or b").is_empty());
+    }
+
+    #[test]
+    fn test_configured_exceptions() {
+        let mut rule = ArticleAgreement::default();
+        let options: toml::Table = toml::from_str(r#"a = ["SQL"]"#).unwrap();
+        rule.configure(&options).unwrap();
+
+        assert!(disagreements(&rule, "a SQL query").is_empty());
+    }
+}
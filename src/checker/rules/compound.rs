@@ -0,0 +1,106 @@
+use super::{Rule, RuleContext, RuleMatch};
+use crate::checker::suggestions;
+use crate::parser::TextSpan;
+
+/// Two adjacent words written apart that form one word, e.g. "mis spelled", or a closed
+/// compound from the misspellings table such as "data base"
+pub struct CompoundWords;
+
+impl Rule for CompoundWords {
+    fn id(&self) -> &'static str {
+        "check-compound"
+    }
+
+    fn description(&self) -> &'static str {
+        "Words written apart that should be one word"
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<RuleMatch> {
+        let mut matches = Vec::new();
+        let mut index = 0;
+
+        while index + 1 < context.spans.len() {
+            match join(context, &context.spans[index], &context.spans[index + 1]) {
+                Some(found) => {
+                    matches.push(found);
+                    index += 2;
+                }
+                None => index += 1,
+            }
+        }
+
+        matches
+    }
+}
+
+fn join(context: &RuleContext, first: &TextSpan, second: &TextSpan) -> Option<RuleMatch> {
+    // Only words separated by spaces on one line, with known offsets, can be joined
+    if first.line != second.line
+        || first.start >= first.end
+        || second.start >= second.end
+        || first.end >= second.start
+        || !context.content[first.end..second.start]
+            .chars()
+            .all(|c| c == ' ')
+    {
+        return None;
+    }
+
+    let first_lower = first.text.to_lowercase();
+    let second_lower = second.text.to_lowercase();
+    let phrase = format!("{} {}", first_lower, second_lower);
+    let joined = format!("{}{}", first_lower, second_lower);
+
+    let (corrections, confidence) = match context.misspellings.corrections(&phrase) {
+        Some(corrections) => (
+            corrections.to_vec(),
+            if corrections.len() == 1 { 1.0 } else { 0.5 },
+        ),
+        // Otherwise one half must be misspelled on its own
+        None if (!(context.is_known)(&first_lower) || !(context.is_known)(&second_lower))
+            && (context.is_known)(&joined) =>
+        {
+            let corrections = vec![joined];
+            let confidence = suggestions::confidence(&phrase, &corrections, context.dictionary);
+            (corrections, confidence)
+        }
+        None => return None,
+    };
+
+    let word = &context.content[first.start..second.end];
+    Some(RuleMatch {
+        start: first.start,
+        end: second.end,
+        suggestions: corrections
+            .iter()
+            .map(|correction| suggestions::match_case(word, correction))
+            .collect(),
+        confidence,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checker::rules::tests::check;
+    use crate::parser::FileType;
+
+    #[test]
+    fn test_joins_words_written_apart() {
+        let words = ["data", "base", "database", "spelled", "misspelled", "the"];
+        let found = check(
+            &CompoundWords,
+            FileType::PlainText,
+            "The Data base was mis spelled\nthe data\nbase",
+            &words,
+        );
+
+        assert_eq!(
+            found,
+            vec![
+                ("Data base".to_string(), vec!["Database".to_string()]),
+                ("mis spelled".to_string(), vec!["misspelled".to_string()]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,231 @@
+pub mod article;
+pub mod compound;
+pub mod rare;
+pub mod repeated;
+pub mod variant;
+
+use crate::checker::dictionary::Dictionary;
+use crate::checker::misspellings::Misspellings;
+use crate::parser::{FileType, TextSpan};
+use crate::{Config, Severity};
+use anyhow::{bail, Context, Result};
+use serde::de::DeserializeOwned;
+
+/// What a rule gets to look at for one file
+pub struct RuleContext<'a> {
+    pub content: &'a str,
+    pub file_type: FileType,
+    /// Configured language, e.g. "en_GB"
+    pub language: &'a str,
+    /// Words extracted by the parser, in document order
+    pub spans: &'a [TextSpan],
+    pub dictionary: &'a Dictionary,
+    pub misspellings: &'a Misspellings,
+    /// Whether a lowercased word is accepted by a dictionary layer or the main dictionary
+    pub is_known: &'a dyn Fn(&str) -> bool,
+    /// Whether a dictionary layer lists a lowercased word, accepted or not
+    pub in_layer: &'a dyn Fn(&str) -> bool,
+}
+
+/// A byte range of content reported by a rule
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleMatch {
+    pub start: usize,
+    pub end: usize,
+    pub suggestions: Vec<String>,
+    /// How sure the rule is that the first suggestion is right (0–1)
+    pub confidence: f64,
+}
+
+/// A check that runs over the parsed words of a file
+pub trait Rule: Send + Sync {
+    /// Name used in `enabled_rules` and `[rules.<id>]` tables
+    fn id(&self) -> &'static str;
+
+    fn description(&self) -> &'static str;
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    /// Apply the rule-specific options of its `[rules.<id>]` table
+    fn configure(&mut self, options: &toml::Table) -> Result<()> {
+        if let Some(option) = options.keys().next() {
+            bail!("Unknown option '{}'", option);
+        }
+        Ok(())
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<RuleMatch>;
+}
+
+/// Every built-in rule with default options
+pub fn builtin() -> Vec<Box<dyn Rule>> {
+    vec![
+        Box::new(compound::CompoundWords),
+        Box::new(rare::RareWords::default()),
+        Box::new(repeated::RepeatedWords::default()),
+        Box::new(article::ArticleAgreement::default()),
+        Box::new(variant::SpellingVariant::default()),
+    ]
+}
+
+/// Deserialize a rule's options, rejecting keys it doesn't know
+pub fn parse_options<T: DeserializeOwned>(options: &toml::Table) -> Result<T> {
+    Ok(toml::Value::Table(options.clone()).try_into()?)
+}
+
+/// A rule enabled for this project
+pub struct ConfiguredRule {
+    pub rule: Box<dyn Rule>,
+    pub severity: Severity,
+}
+
+/// Rules named in `enabled_rules`, configured from their `[rules.<id>]` tables
+pub fn enabled(config: &Config) -> Result<Vec<ConfiguredRule>> {
+    let mut rules = Vec::new();
+
+    for id in config.enabled_rules.iter().flatten() {
+        let Some(mut rule) = builtin().into_iter().find(|rule| rule.id() == id) else {
+            eprintln!("Warning: Unknown rule '{}' in enabled_rules", id);
+            continue;
+        };
+
+        let settings = config.rules.get(id).cloned().unwrap_or_default();
+        rule.configure(&settings.options)
+            .with_context(|| format!("Invalid configuration for rule '{}'", id))?;
+        rules.push(ConfiguredRule {
+            severity: settings.severity.unwrap_or(rule.default_severity()),
+            rule,
+        });
+    }
+
+    Ok(rules)
+}
+
+/// A built-in rule as configured for this project
+pub struct RuleStatus {
+    pub id: &'static str,
+    pub description: &'static str,
+    pub enabled: bool,
+    pub severity: Severity,
+}
+
+/// The state of every built-in rule under a config
+pub fn statuses(config: &Config) -> Vec<RuleStatus> {
+    builtin()
+        .into_iter()
+        .map(|rule| RuleStatus {
+            id: rule.id(),
+            description: rule.description(),
+            enabled: config
+                .enabled_rules
+                .iter()
+                .flatten()
+                .any(|id| id == rule.id()),
+            severity: config
+                .rules
+                .get(rule.id())
+                .and_then(|settings| settings.severity)
+                .unwrap_or(rule.default_severity()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RuleConfig;
+    use std::collections::HashMap;
+
+    /// Run a rule over content against a small dictionary, returning each match's text
+    /// and suggestions
+    pub(super) fn check(
+        rule: &dyn Rule,
+        file_type: FileType,
+        content: &str,
+        words: &[&str],
+    ) -> Vec<(String, Vec<String>)> {
+        let words: Vec<(&str, u64)> = words.iter().map(|word| (*word, 0)).collect();
+        check_with_frequencies(rule, file_type, content, &words, &[])
+    }
+
+    /// `check` against a dictionary with word frequencies, with `layered` words
+    /// accepted by a dictionary layer
+    pub(super) fn check_with_frequencies(
+        rule: &dyn Rule,
+        file_type: FileType,
+        content: &str,
+        words: &[(&str, u64)],
+        layered: &[&str],
+    ) -> Vec<(String, Vec<String>)> {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let dict_path = temp_dir.path().join("test.dict");
+        let frequencies: HashMap<String, u64> = words
+            .iter()
+            .map(|(word, frequency)| (word.to_string(), *frequency))
+            .collect();
+        let words: Vec<String> = frequencies.keys().cloned().collect();
+        Dictionary::build_with_frequencies(&words, &frequencies, &dict_path).unwrap();
+        let dictionary = Dictionary::load_from_path(&dict_path).unwrap();
+
+        let spans = crate::parser::parse_content(file_type, content).unwrap();
+        let in_layer = |word: &str| layered.contains(&word);
+        let is_known = |word: &str| in_layer(word) || dictionary.contains(word);
+        let context = RuleContext {
+            content,
+            file_type,
+            language: "en_US",
+            spans: &spans,
+            dictionary: &dictionary,
+            misspellings: &Misspellings::new(&HashMap::new()),
+            is_known: &is_known,
+            in_layer: &in_layer,
+        };
+
+        rule.check(&context)
+            .into_iter()
+            .map(|found| {
+                (
+                    content[found.start..found.end].to_string(),
+                    found.suggestions,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_enabled_rules() {
+        let mut config = Config {
+            enabled_rules: Some(vec![
+                "check-compound".to_string(),
+                "no-such-rule".to_string(),
+            ]),
+            ..Default::default()
+        };
+        config.rules.insert(
+            "check-compound".to_string(),
+            RuleConfig {
+                severity: Some(Severity::Warning),
+                ..Default::default()
+            },
+        );
+
+        let rules = enabled(&config).unwrap();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].rule.id(), "check-compound");
+        assert_eq!(rules[0].severity, Severity::Warning);
+
+        // Options a rule doesn't know are rejected
+        let mut options = toml::Table::new();
+        options.insert("strict".to_string(), toml::Value::Boolean(true));
+        config.rules.insert(
+            "check-compound".to_string(),
+            RuleConfig {
+                severity: None,
+                options,
+            },
+        );
+        assert!(enabled(&config).is_err());
+    }
+}
@@ -0,0 +1,177 @@
+use super::{parse_options, Rule, RuleContext, RuleMatch};
+use crate::checker::suggestions;
+use crate::Severity;
+use anyhow::Result;
+use serde::Deserialize;
+
+/// Valid but rare words one edit from a far more common word, e.g. "fro" for "for"
+pub struct RareWords {
+    /// How many times more frequent the common word must be
+    ratio: u64,
+    /// Rare words that are meant, on top of those a dictionary layer lists
+    allow: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Options {
+    ratio: Option<u64>,
+    #[serde(default)]
+    allow: Vec<String>,
+}
+
+impl Default for RareWords {
+    fn default() -> Self {
+        Self {
+            ratio: 1000,
+            allow: Vec::new(),
+        }
+    }
+}
+
+impl Rule for RareWords {
+    fn id(&self) -> &'static str {
+        "check-rare"
+    }
+
+    fn description(&self) -> &'static str {
+        "Rare words that are likely typos of a common one (fro/for)"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Hint
+    }
+
+    fn configure(&mut self, options: &toml::Table) -> Result<()> {
+        let options: Options = parse_options(options)?;
+        if let Some(ratio) = options.ratio {
+            self.ratio = ratio.max(1);
+        }
+        self.allow
+            .extend(options.allow.iter().map(|word| word.to_lowercase()));
+        Ok(())
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<RuleMatch> {
+        context
+            .spans
+            .iter()
+            .filter(|span| span.start < span.end)
+            .filter_map(|span| {
+                let common = self.common_neighbour(context, &span.text)?;
+                Some(RuleMatch {
+                    start: span.start,
+                    end: span.end,
+                    suggestions: vec![suggestions::match_case(&span.text, &common)],
+                    // The word is valid, so `--fix` leaves it alone by default
+                    confidence: 0.5,
+                })
+            })
+            .collect()
+    }
+}
+
+impl RareWords {
+    /// The most frequent word one edit away, if it is `ratio` times as common as `word`
+    fn common_neighbour(&self, context: &RuleContext, word: &str) -> Option<String> {
+        // Acronyms and names in mixed case are not typos of common words
+        if word.chars().skip(1).any(char::is_uppercase) {
+            return None;
+        }
+        let lower = word.to_lowercase();
+        let dictionary = context.dictionary;
+        if !dictionary.contains(&lower) || self.allow.contains(&lower) || (context.in_layer)(&lower)
+        {
+            return None;
+        }
+        // Without frequency data there is nothing to compare
+        let frequency = dictionary.frequency(&lower);
+        if frequency == 0 {
+            return None;
+        }
+
+        // Transposed letters count as one edit ("fro" / "for")
+        let chars: Vec<char> = lower.chars().collect();
+        let transposed = (1..chars.len()).filter_map(|i| {
+            let mut swapped = chars.clone();
+            swapped.swap(i - 1, i);
+            let swapped: String = swapped.into_iter().collect();
+            (dictionary.contains(&swapped) && dictionary.is_suggestible(&swapped))
+                .then_some(swapped)
+        });
+
+        let threshold = frequency.saturating_mul(self.ratio);
+        dictionary
+            .fuzzy_search(&lower, 1)?
+            .into_iter()
+            .chain(transposed)
+            .filter(|candidate| *candidate != lower)
+            .map(|candidate| (dictionary.frequency(&candidate), candidate))
+            .filter(|(frequency, _)| *frequency >= threshold)
+            .max()
+            .map(|(_, candidate)| candidate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checker::rules::tests::check_with_frequencies;
+    use crate::parser::FileType;
+
+    fn rare(rule: &RareWords, content: &str, layered: &[&str]) -> Vec<(String, Vec<String>)> {
+        let words = [
+            ("for", 5_000_000),
+            ("fro", 900),
+            ("from", 3_000_000),
+            ("form", 200_000),
+            ("the", 9_000_000),
+            ("thee", 2_000),
+            ("tho", 300),
+            ("fo", 0),
+            ("to", 7_000_000),
+            ("and", 8_000_000),
+        ];
+        check_with_frequencies(rule, FileType::PlainText, content, &words, layered)
+    }
+
+    #[test]
+    fn test_rare_words() {
+        let rule = RareWords::default();
+        assert_eq!(
+            rare(&rule, "Fro the form, to and fro from thee tho", &[]),
+            vec![
+                ("Fro".to_string(), vec!["For".to_string()]),
+                ("fro".to_string(), vec!["for".to_string()]),
+                ("thee".to_string(), vec!["the".to_string()]),
+                ("tho".to_string(), vec!["the".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rare_skips_layered_and_unknown() {
+        let rule = RareWords::default();
+        // "thee" is in a project word list and "fo" has no frequency to compare
+        assert_eq!(
+            rare(&rule, "fro, thee, fo", &["thee"]),
+            vec![("fro".to_string(), vec!["for".to_string()])]
+        );
+    }
+
+    #[test]
+    fn test_rare_options() {
+        let mut rule = RareWords::default();
+        let options: toml::Table = toml::from_str("ratio = 5000\nallow = [\"Fro\"]").unwrap();
+        rule.configure(&options).unwrap();
+
+        // "thee" is now too common to flag and "fro" is allowed
+        assert_eq!(
+            rare(&rule, "to and fro, thee tho", &[]),
+            vec![("tho".to_string(), vec!["the".to_string()])]
+        );
+
+        let options: toml::Table = toml::from_str("strict = true").unwrap();
+        assert!(rule.configure(&options).is_err());
+    }
+}
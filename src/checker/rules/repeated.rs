@@ -0,0 +1,170 @@
+use super::{parse_options, Rule, RuleContext, RuleMatch};
+use crate::parser::{markdown, FileType, TextSpan};
+use anyhow::Result;
+use serde::Deserialize;
+
+/// A word immediately repeated within one block of text, e.g. "the the"
+pub struct RepeatedWords {
+    /// Words that may legitimately repeat ("had had", "that that")
+    allow: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Options {
+    allow: Vec<String>,
+}
+
+impl Default for RepeatedWords {
+    fn default() -> Self {
+        Self {
+            allow: vec!["had".to_string(), "that".to_string()],
+        }
+    }
+}
+
+impl Rule for RepeatedWords {
+    fn id(&self) -> &'static str {
+        "repeated-word"
+    }
+
+    fn description(&self) -> &'static str {
+        "The same word twice in a row"
+    }
+
+    fn configure(&mut self, options: &toml::Table) -> Result<()> {
+        if !options.is_empty() {
+            let options: Options = parse_options(options)?;
+            self.allow = options
+                .allow
+                .iter()
+                .map(|word| word.to_lowercase())
+                .collect();
+        }
+        Ok(())
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<RuleMatch> {
+        // Markdown blocks can sit on adjacent lines, e.g. a heading and its paragraph
+        let boundaries = match context.file_type {
+            FileType::Markdown => markdown::block_boundaries(context.content),
+            _ => Vec::new(),
+        };
+
+        let spans = context.spans;
+        let mut matches = Vec::new();
+        let mut index = 0;
+
+        while index < spans.len() {
+            // A run of repeats collapses to its first word
+            let mut last = index;
+            while last + 1 < spans.len()
+                && self.repeats(context.content, &boundaries, &spans[last], &spans[last + 1])
+            {
+                last += 1;
+            }
+
+            if last > index {
+                let first = &spans[index];
+                matches.push(RuleMatch {
+                    start: first.start,
+                    end: spans[last].end,
+                    suggestions: vec![context.content[first.start..first.end].to_string()],
+                    confidence: 1.0,
+                });
+            }
+            index = last + 1;
+        }
+
+        matches
+    }
+}
+
+impl RepeatedWords {
+    /// Whether `second` repeats `first` with only whitespace, and at most one line
+    /// break, between them
+    fn repeats(
+        &self,
+        content: &str,
+        boundaries: &[usize],
+        first: &TextSpan,
+        second: &TextSpan,
+    ) -> bool {
+        if first.start >= first.end || second.start >= second.end || first.end >= second.start {
+            return false;
+        }
+
+        let between = &content[first.end..second.start];
+        if !between.chars().all(char::is_whitespace) || between.matches('\n').count() > 1 {
+            return false;
+        }
+
+        let word = first.text.to_lowercase();
+        if word != second.text.to_lowercase() || self.allow.contains(&word) {
+            return false;
+        }
+
+        // No block starts or ends between the two words
+        let next = boundaries.partition_point(|&offset| offset < first.end);
+        boundaries
+            .get(next)
+            .is_none_or(|&offset| offset > second.start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checker::rules::tests::check;
+
+    fn repeated(file_type: FileType, content: &str) -> Vec<(String, Vec<String>)> {
+        check(&RepeatedWords::default(), file_type, content, &[])
+    }
+
+    #[test]
+    fn test_repeated_words() {
+        assert_eq!(
+            repeated(
+                FileType::PlainText,
+                "The the cat is is here.\nHe had had enough of the\nthe dog"
+            ),
+            vec![
+                ("The the".to_string(), vec!["The".to_string()]),
+                ("is is".to_string(), vec!["is".to_string()]),
+                ("the\nthe".to_string(), vec!["the".to_string()]),
+            ]
+        );
+
+        // A blank line separates paragraphs
+        assert!(repeated(FileType::PlainText, "the end of the\n\nthe start").is_empty());
+        assert_eq!(
+            repeated(FileType::PlainText, "go go go now"),
+            vec![("go go go".to_string(), vec!["go".to_string()])]
+        );
+    }
+
+    #[test]
+    fn test_repeated_words_in_markdown() {
+        let content = "# Setup the\nthe steps\n\n- item\n- item\n\nUse `the` the tool and the\nthe rest.\n\n```\nthe the\n```\n";
+        assert_eq!(
+            repeated(FileType::Markdown, content),
+            vec![("the\nthe".to_string(), vec!["the".to_string()])]
+        );
+    }
+
+    #[test]
+    fn test_allow_option() {
+        let mut rule = RepeatedWords::default();
+        let options: toml::Table = toml::from_str(r#"allow = ["very"]"#).unwrap();
+        rule.configure(&options).unwrap();
+
+        let found = check(&rule, FileType::PlainText, "a very very long long day", &[]);
+        assert_eq!(
+            found,
+            vec![("long long".to_string(), vec!["long".to_string()])]
+        );
+
+        let options: toml::Table = toml::from_str("strict = true").unwrap();
+        assert!(rule.configure(&options).is_err());
+    }
+}
@@ -0,0 +1,196 @@
+use super::{parse_options, Rule, RuleContext, RuleMatch};
+use crate::checker::suggestions;
+use crate::checker::variants::{Variant, Variants};
+use crate::Severity;
+use anyhow::Result;
+use serde::Deserialize;
+
+/// American spellings in a British project or vice versa, e.g. "color" / "colour"
+#[derive(Default)]
+pub struct SpellingVariant {
+    variants: Variants,
+    /// Follows `language` when unset
+    prefer: Option<Preference>,
+}
+
+/// Which spellings a project accepts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Preference {
+    American,
+    British,
+    /// Whichever variant each file mostly uses
+    Consistent,
+}
+
+impl Preference {
+    fn for_language(language: &str) -> Self {
+        match language {
+            "en_US" => Preference::American,
+            "en_GB" => Preference::British,
+            _ => Preference::Consistent,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Options {
+    prefer: Option<Preference>,
+}
+
+impl Rule for SpellingVariant {
+    fn id(&self) -> &'static str {
+        "spelling-variant"
+    }
+
+    fn description(&self) -> &'static str {
+        "American and British spellings mixed (color/colour)"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn configure(&mut self, options: &toml::Table) -> Result<()> {
+        let options: Options = parse_options(options)?;
+        self.prefer = options.prefer;
+        Ok(())
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<RuleMatch> {
+        let content = context.content;
+        let found: Vec<_> = context
+            .spans
+            .iter()
+            .filter(|span| span.start < span.end && is_whole_word(content, span.start, span.end))
+            .filter_map(|span| {
+                self.variants
+                    .classify(&span.text.to_lowercase(), context.is_known)
+                    .map(|(variant, counterpart)| (span, variant, counterpart))
+            })
+            .collect();
+
+        let preferred = match self
+            .prefer
+            .unwrap_or_else(|| Preference::for_language(context.language))
+        {
+            Preference::American => Variant::American,
+            Preference::British => Variant::British,
+            Preference::Consistent => {
+                let Some((_, first, _)) = found.first() else {
+                    return Vec::new();
+                };
+                let american = found
+                    .iter()
+                    .filter(|(_, variant, _)| *variant == Variant::American)
+                    .count();
+                // Ties go to the variant used first
+                match (american * 2).cmp(&found.len()) {
+                    std::cmp::Ordering::Greater => Variant::American,
+                    std::cmp::Ordering::Less => Variant::British,
+                    std::cmp::Ordering::Equal => *first,
+                }
+            }
+        };
+
+        found
+            .into_iter()
+            .filter(|(_, variant, _)| *variant != preferred)
+            .map(|(span, _, counterpart)| RuleMatch {
+                start: span.start,
+                end: span.end,
+                suggestions: vec![suggestions::match_case(&span.text, &counterpart)],
+                confidence: 0.9,
+            })
+            .collect()
+    }
+}
+
+/// Whether a range is a word of its own rather than part of an identifier like `bgColor`
+fn is_whole_word(content: &str, start: usize, end: usize) -> bool {
+    let joined = |c: char| c.is_alphanumeric() || c == '_';
+    !content[..start].chars().next_back().is_some_and(joined)
+        && !content[end..].chars().next().is_some_and(joined)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checker::rules::tests::check;
+    use crate::parser::FileType;
+
+    fn variants(prefer: &str, content: &str) -> Vec<(String, Vec<String>)> {
+        let mut rule = SpellingVariant::default();
+        let options: toml::Table = toml::from_str(&format!("prefer = \"{}\"", prefer)).unwrap();
+        rule.configure(&options).unwrap();
+        check(
+            &rule,
+            FileType::PlainText,
+            content,
+            &["realised", "realized"],
+        )
+    }
+
+    #[test]
+    fn test_preferred_variant() {
+        let content = "The Colour of the defence, realized with color.";
+        assert_eq!(
+            variants("american", content),
+            vec![
+                ("Colour".to_string(), vec!["Color".to_string()]),
+                ("defence".to_string(), vec!["defense".to_string()]),
+            ]
+        );
+        assert_eq!(
+            variants("british", content),
+            vec![
+                ("realized".to_string(), vec!["realised".to_string()]),
+                ("color".to_string(), vec!["colour".to_string()]),
+            ]
+        );
+
+        // Identifiers are left alone
+        assert!(variants("british", "set bgColor and text_color").is_empty());
+    }
+
+    #[test]
+    fn test_licence() {
+        let content = "A licence to license";
+        assert_eq!(
+            variants("american", content),
+            vec![("licence".to_string(), vec!["license".to_string()])]
+        );
+        assert!(variants("british", content).is_empty());
+    }
+
+    #[test]
+    fn test_consistent_per_file() {
+        assert_eq!(
+            variants("consistent", "colour, favour and color"),
+            vec![("color".to_string(), vec!["colour".to_string()])]
+        );
+        assert_eq!(
+            variants("consistent", "color and colour"),
+            vec![("colour".to_string(), vec!["color".to_string()])]
+        );
+        assert!(variants("consistent", "colour and favour").is_empty());
+    }
+
+    #[test]
+    fn test_preference_follows_language() {
+        // The test context checks as en_US
+        let found = check(
+            &SpellingVariant::default(),
+            FileType::PlainText,
+            "colour",
+            &[],
+        );
+        assert_eq!(
+            found,
+            vec![("colour".to_string(), vec!["color".to_string()])]
+        );
+        assert_eq!(Preference::for_language("en_GB"), Preference::British);
+        assert_eq!(Preference::for_language("en_AU"), Preference::Consistent);
+    }
+}
@@ -1,7 +1,193 @@
 use crate::checker::dictionary::Dictionary;
+use crate::checker::keyboard::KeyboardLayout;
+use crate::checker::phonetic::PhoneticEncoder;
+use std::collections::HashMap;
 
-/// Generate spelling suggestions using edit distance
-pub fn generate(word: &str, dictionary: &Dictionary, max_suggestions: usize) -> Vec<String> {
+/// Largest Levenshtein distance searched in the dictionary; transpositions cost two here
+const SEARCH_DISTANCE: u32 = 2;
+
+/// Ranking bonus for the most frequent candidate, in edits
+const FREQUENCY_WEIGHT: f64 = 0.5;
+
+/// Cost of hitting a key next to the intended one
+const ADJACENT_KEY_COST: f64 = 0.5;
+
+/// Cost of typing a letter twice or only once where it should be doubled
+const DOUBLED_LETTER_COST: f64 = 0.5;
+
+/// Base cost of a sound-alike match, between one and two edits
+const PHONETIC_COST: f64 = 1.5;
+
+/// Cost of a missed space bar, splitting a word in two
+const SPLIT_COST: f64 = 0.5;
+
+/// Generate spelling suggestions within two edits or sounding alike, best first
+///
+/// Searches the whole dictionary with a Levenshtein automaton, counting an adjacent
+/// transposition ("teh" → "the") as one edit, and adds words with the same phonetic
+/// code when an encoder is given ("fonetik" → "phonetic") and two-word splits ("ofthe"
+/// → "of the"). Candidates are ranked by a typing-error cost on the given keyboard
+/// layout, then by word frequency.
+pub fn generate(
+    word: &str,
+    dictionary: &Dictionary,
+    layout: KeyboardLayout,
+    phonetic: Option<&dyn PhoneticEncoder>,
+    max_suggestions: usize,
+) -> Vec<String> {
+    let length = word.chars().count();
+    // Short words have too many two-edit neighbours to be useful suggestions
+    let max_distance = if length <= 3 { 1 } else { 2 };
+
+    // Very long words can exceed the automaton's state limit at distance 2
+    let mut costs: HashMap<String, f64> = dictionary
+        .fuzzy_search(word, SEARCH_DISTANCE)
+        .or_else(|| dictionary.fuzzy_search(word, 1))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|candidate| candidate != word && edit_distance(word, candidate) <= max_distance)
+        .map(|candidate| {
+            let cost = typing_cost(word, &candidate, layout);
+            (candidate, cost)
+        })
+        .collect();
+
+    // Sound-alikes may be several edits away, but not a different word altogether
+    if let Some(encoder) = phonetic.filter(|_| length > 3) {
+        let max_phonetic_distance = (length / 2 + 1).max(3);
+        for candidate in dictionary.sounds_like(&encoder.encode(word)) {
+            let distance = edit_distance(word, &candidate);
+            if candidate == word || distance > max_phonetic_distance {
+                continue;
+            }
+            let cost = PHONETIC_COST + 0.1 * distance as f64;
+            costs
+                .entry(candidate)
+                .and_modify(|existing| *existing = existing.min(cost))
+                .or_insert(cost);
+        }
+    }
+
+    for split in splits(word, dictionary) {
+        costs.insert(split, SPLIT_COST);
+    }
+
+    // Frequencies are compared on a log scale relative to the most common candidate
+    let frequency = |candidate: &str| {
+        candidate
+            .split(' ')
+            .map(|part| dictionary.frequency(part))
+            .min()
+            .unwrap_or(0)
+    };
+    let max_frequency = costs.keys().map(|c| frequency(c)).max().unwrap_or(0);
+    let mut ranked: Vec<(f64, String)> = costs
+        .into_iter()
+        .map(|(candidate, mut score)| {
+            if max_frequency > 0 {
+                score -= FREQUENCY_WEIGHT * (frequency(&candidate) as f64).ln_1p()
+                    / (max_frequency as f64).ln_1p();
+            }
+            (score, candidate)
+        })
+        .collect();
+
+    // Best score first, then the most similar length, then alphabetical for stable output
+    ranked.sort_by(|(sa, a), (sb, b)| {
+        sa.total_cmp(sb)
+            .then_with(|| {
+                a.len()
+                    .abs_diff(word.len())
+                    .cmp(&b.len().abs_diff(word.len()))
+            })
+            .then_with(|| a.cmp(b))
+    });
+
+    ranked
+        .into_iter()
+        .take(max_suggestions)
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
+
+/// Apply the casing pattern of `original` to a correction: "Teh" → "The",
+/// "RECIEVE" → "RECEIVE", "getRecieveData" → "getReceiveData"
+///
+/// A lowercase original keeps the correction's own casing (e.g. "github" → "GitHub").
+pub fn match_case(original: &str, replacement: &str) -> String {
+    let letters: Vec<char> = original.chars().filter(|c| c.is_alphabetic()).collect();
+    let uppercase = letters.iter().filter(|c| c.is_uppercase()).count();
+
+    if uppercase == 0 {
+        return replacement.to_string();
+    }
+    if uppercase == letters.len() && letters.len() > 1 {
+        return replacement.to_uppercase();
+    }
+    if uppercase == 1 && letters[0].is_uppercase() {
+        let mut chars = replacement.chars();
+        return match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => String::new(),
+        };
+    }
+
+    // Mixed case like camelCase: copy the case of the unchanged prefix and suffix
+    let original: Vec<char> = original.chars().collect();
+    let replacement: Vec<char> = replacement.chars().collect();
+    let same = |a: &char, b: &char| a.to_lowercase().eq(b.to_lowercase());
+    let prefix = original
+        .iter()
+        .zip(&replacement)
+        .take_while(|(a, b)| same(a, b))
+        .count();
+    let suffix = original
+        .iter()
+        .rev()
+        .zip(replacement.iter().rev())
+        .take(original.len().min(replacement.len()) - prefix)
+        .take_while(|(a, b)| same(a, b))
+        .count();
+
+    replacement
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &c)| {
+            let source = if i < prefix {
+                Some(original[i])
+            } else if i >= replacement.len() - suffix {
+                Some(original[original.len() - (replacement.len() - i)])
+            } else {
+                None
+            };
+            match source {
+                Some(source) if source.is_uppercase() => c.to_uppercase().collect::<Vec<_>>(),
+                _ => vec![c],
+            }
+        })
+        .collect()
+}
+
+/// Ways to split `word` into two suggestible words of at least two letters each
+fn splits(word: &str, dictionary: &Dictionary) -> Vec<String> {
+    let is_word = |part: &str| {
+        part.chars().count() >= 2 && dictionary.contains(part) && dictionary.is_suggestible(part)
+    };
+
+    word.char_indices()
+        .skip(1)
+        .map(|(i, _)| word.split_at(i))
+        .filter(|(left, right)| is_word(left) && is_word(right))
+        .map(|(left, right)| format!("{} {}", left, right))
+        .collect()
+}
+
+/// Prefix-scan strategy used before the Levenshtein automaton, kept for benchmarks
+pub fn generate_by_prefix(
+    word: &str,
+    dictionary: &Dictionary,
+    max_suggestions: usize,
+) -> Vec<String> {
     // Try progressively more expensive operations
     let mut suggestions = Vec::new();
 
@@ -27,7 +213,10 @@ pub fn generate(word: &str, dictionary: &Dictionary, max_suggestions: usize) ->
     // 2. Try common transformations (medium speed)
     let transformations = generate_transformations(word);
     for transform in transformations {
-        if dictionary.contains(&transform) && !suggestions.contains(&transform) {
+        if dictionary.contains(&transform)
+            && dictionary.is_suggestible(&transform)
+            && !suggestions.contains(&transform)
+        {
             suggestions.push(transform);
             if suggestions.len() >= max_suggestions {
                 suggestions.truncate(max_suggestions);
@@ -93,10 +282,41 @@ pub fn generate(word: &str, dictionary: &Dictionary, max_suggestions: usize) ->
     suggestions
 }
 
-/// Calculate Levenshtein distance between two strings
+/// Confidence (0–1) that the first suggestion is the intended word
+pub fn confidence(word: &str, suggestions: &[String], dictionary: &Dictionary) -> f64 {
+    let Some(top) = suggestions.first() else {
+        return 0.0;
+    };
+
+    let distance = edit_distance(word, top);
+    let mut score = match distance {
+        0 | 1 => 0.9,
+        2 => 0.6,
+        _ => 0.3,
+    };
+
+    // Another suggestion just as close makes the choice a guess, unless it is far rarer
+    let top_frequency = dictionary.frequency(top);
+    if suggestions[1..].iter().any(|other| {
+        edit_distance(word, other) <= distance
+            && top_frequency < 10 * dictionary.frequency(other).max(1)
+    }) {
+        score *= 0.5;
+    }
+
+    // Short words have many near neighbours
+    if word.chars().count() <= 3 {
+        score *= 0.7;
+    }
+
+    score
+}
+
+/// Optimal string alignment distance: Levenshtein plus adjacent transpositions as one edit
 fn edit_distance(a: &str, b: &str) -> usize {
-    let a_len = a.chars().count();
-    let b_len = b.chars().count();
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    let (a_len, b_len) = (a_chars.len(), b_chars.len());
 
     if a_len == 0 {
         return b_len;
@@ -114,26 +334,86 @@ fn edit_distance(a: &str, b: &str) -> usize {
         *item = j;
     }
 
-    let a_chars: Vec<char> = a.chars().collect();
-    let b_chars: Vec<char> = b.chars().collect();
-
-    for (i, a_char) in a_chars.iter().enumerate() {
-        for (j, b_char) in b_chars.iter().enumerate() {
-            let cost = if a_char == b_char { 0 } else { 1 };
+    for i in 1..=a_len {
+        for j in 1..=b_len {
+            let cost = if a_chars[i - 1] == b_chars[j - 1] {
+                0
+            } else {
+                1
+            };
 
-            matrix[i + 1][j + 1] = std::cmp::min(
+            matrix[i][j] = std::cmp::min(
                 std::cmp::min(
-                    matrix[i][j + 1] + 1, // deletion
-                    matrix[i + 1][j] + 1, // insertion
+                    matrix[i - 1][j] + 1, // deletion
+                    matrix[i][j - 1] + 1, // insertion
                 ),
-                matrix[i][j] + cost, // substitution
+                matrix[i - 1][j - 1] + cost, // substitution
             );
+
+            // Transposition of two adjacent characters
+            if i > 1
+                && j > 1
+                && a_chars[i - 1] == b_chars[j - 2]
+                && a_chars[i - 2] == b_chars[j - 1]
+            {
+                matrix[i][j] = matrix[i][j].min(matrix[i - 2][j - 2] + 1);
+            }
         }
     }
 
     matrix[a_len][b_len]
 }
 
+/// Edit distance weighted by how likely each edit is as a typing mistake on `layout`
+///
+/// Like `edit_distance`, but hitting a neighbouring key and doubling or missing a
+/// doubled letter ("untill", "hapy") are cheaper than arbitrary edits.
+fn typing_cost(typo: &str, intended: &str, layout: KeyboardLayout) -> f64 {
+    let a: Vec<char> = typo.chars().collect();
+    let b: Vec<char> = intended.chars().collect();
+
+    let mut matrix = vec![vec![0.0; b.len() + 1]; a.len() + 1];
+    for i in 1..=a.len() {
+        matrix[i][0] = matrix[i - 1][0] + extra_letter_cost(&a, i - 1);
+    }
+    for j in 1..=b.len() {
+        matrix[0][j] = matrix[0][j - 1] + extra_letter_cost(&b, j - 1);
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let substitution = if a[i - 1] == b[j - 1] {
+                0.0
+            } else if layout.are_adjacent(a[i - 1], b[j - 1]) {
+                ADJACENT_KEY_COST
+            } else {
+                1.0
+            };
+
+            matrix[i][j] = (matrix[i - 1][j] + extra_letter_cost(&a, i - 1)) // extra letter typed
+                .min(matrix[i][j - 1] + extra_letter_cost(&b, j - 1)) // letter left out
+                .min(matrix[i - 1][j - 1] + substitution);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                matrix[i][j] = matrix[i][j].min(matrix[i - 2][j - 2] + 1.0);
+            }
+        }
+    }
+
+    matrix[a.len()][b.len()]
+}
+
+/// Cost of inserting or deleting `letters[index]`, cheaper when it repeats its neighbour
+fn extra_letter_cost(letters: &[char], index: usize) -> f64 {
+    let doubled = (index > 0 && letters[index - 1] == letters[index])
+        || letters.get(index + 1) == Some(&letters[index]);
+    if doubled {
+        DOUBLED_LETTER_COST
+    } else {
+        1.0
+    }
+}
+
 /// Generate common transformations of a word
 fn generate_transformations(word: &str) -> Vec<String> {
     let mut transformations = Vec::new();
@@ -184,6 +464,9 @@ fn generate_transformations(word: &str) -> Vec<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::checker::phonetic::DoubleMetaphone;
+
+    const QWERTY: KeyboardLayout = KeyboardLayout::Qwerty;
 
     #[test]
     fn test_edit_distance() {
@@ -191,6 +474,152 @@ mod tests {
         assert_eq!(edit_distance("hello", "hallo"), 1);
         assert_eq!(edit_distance("hello", "hullo"), 1);
         assert_eq!(edit_distance("hello", "world"), 4);
+        assert_eq!(edit_distance("teh", "the"), 1);
+        assert_eq!(edit_distance("wrod", "word"), 1);
+    }
+
+    #[test]
+    fn test_typing_cost() {
+        let qwerty = KeyboardLayout::Qwerty;
+        assert_eq!(typing_cost("dat", "cat", qwerty), 0.5);
+        assert_eq!(typing_cost("zat", "cat", qwerty), 1.0);
+        assert_eq!(typing_cost("ppeople", "people", qwerty), 0.5);
+        assert_eq!(typing_cost("hapy", "happy", qwerty), 0.5);
+        assert_eq!(typing_cost("teh", "the", qwerty), 1.0);
+
+        // "z" and "u" are neighbours only where "z" replaces "y"
+        assert_eq!(typing_cost("zu", "uu", qwerty), 1.0);
+        assert_eq!(typing_cost("zu", "uu", KeyboardLayout::Qwertz), 0.5);
+    }
+
+    fn dictionary(words: &[(&str, u64)]) -> Dictionary {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let dict_path = temp_dir.path().join("test.dict");
+        let frequencies: HashMap<String, u64> = words
+            .iter()
+            .map(|(word, frequency)| (word.to_string(), *frequency))
+            .collect();
+        let words: Vec<String> = frequencies.keys().cloned().collect();
+        Dictionary::build_with_frequencies(&words, &frequencies, &dict_path).unwrap();
+        Dictionary::build_phonetic_index(
+            &words,
+            &DoubleMetaphone::default(),
+            &Dictionary::phonetic_path(&dict_path),
+        )
+        .unwrap();
+        Dictionary::load_from_path(&dict_path).unwrap()
+    }
+
+    #[test]
+    fn test_generate_finds_early_typos() {
+        let dictionary = dictionary(&[
+            ("people", 0),
+            ("purple", 0),
+            ("the", 0),
+            ("then", 0),
+            ("word", 0),
+            ("world", 0),
+            ("wrong", 0),
+        ]);
+
+        assert_eq!(generate("teh", &dictionary, QWERTY, None, 5), vec!["the"]);
+        assert_eq!(
+            generate("wrod", &dictionary, QWERTY, None, 2),
+            vec!["word", "world"]
+        );
+        assert_eq!(
+            generate("ppeople", &dictionary, QWERTY, None, 5)[0],
+            "people"
+        );
+    }
+
+    #[test]
+    fn test_ranking_uses_keyboard_and_frequency() {
+        // "d" sits next to "c", so without frequencies "cat" is the likelier slip
+        let uniform = dictionary(&[("bat", 0), ("cat", 0)]);
+        assert_eq!(
+            generate("dat", &uniform, QWERTY, None, 2),
+            vec!["cat", "bat"]
+        );
+
+        // "g" touches "b" on QWERTY but "c" on Dvorak
+        assert_eq!(
+            generate("gat", &uniform, QWERTY, None, 2),
+            vec!["bat", "cat"]
+        );
+        assert_eq!(
+            generate("gat", &uniform, KeyboardLayout::Dvorak, None, 2),
+            vec!["cat", "bat"]
+        );
+
+        // A much more common word wins among equally likely typos
+        let weighted = dictionary(&[("bat", 1), ("cat", 1000), ("thew", 2), ("the", 5000)]);
+        assert_eq!(
+            generate("zat", &weighted, QWERTY, None, 2),
+            vec!["cat", "bat"]
+        );
+        assert_eq!(
+            generate("thw", &weighted, QWERTY, None, 2),
+            vec!["the", "thew"]
+        );
+    }
+
+    #[test]
+    fn test_phonetic_candidates() {
+        let dictionary = dictionary(&[("phonetic", 0), ("knowledge", 0), ("college", 0)]);
+        let encoder = DoubleMetaphone::default();
+
+        assert!(generate("fonetik", &dictionary, QWERTY, None, 5).is_empty());
+        assert_eq!(
+            generate("fonetik", &dictionary, QWERTY, Some(&encoder), 5),
+            vec!["phonetic"]
+        );
+        assert_eq!(
+            generate("nolige", &dictionary, QWERTY, Some(&encoder), 5),
+            vec!["knowledge"]
+        );
+    }
+
+    #[test]
+    fn test_split_candidates() {
+        let dictionary = dictionary(&[("of", 0), ("the", 0), ("other", 0), ("a", 0), ("lot", 0)]);
+
+        assert_eq!(
+            generate("ofthe", &dictionary, QWERTY, None, 5),
+            vec!["of the", "other", "the"]
+        );
+        // Single letters are too easy to split off
+        assert!(!generate("alot", &dictionary, QWERTY, None, 5).contains(&"a lot".to_string()));
+    }
+
+    #[test]
+    fn test_match_case() {
+        assert_eq!(match_case("teh", "the"), "the");
+        assert_eq!(match_case("Teh", "the"), "The");
+        assert_eq!(match_case("RECIEVE", "receive"), "RECEIVE");
+        assert_eq!(match_case("Ofthe", "of the"), "Of the");
+        assert_eq!(match_case("github", "GitHub"), "GitHub");
+        assert_eq!(
+            match_case("getRecieveData", "getreceivedata"),
+            "getReceiveData"
+        );
+        assert_eq!(match_case("HTTPServr", "httpserver"), "HTTPServer");
+        assert_eq!(match_case("I", "it"), "It");
+    }
+
+    #[test]
+    fn test_confidence() {
+        let uniform = dictionary(&[]);
+        let unique = vec!["receive".to_string(), "relieve".to_string()];
+        let tied = vec!["cat".to_string(), "bat".to_string()];
+
+        assert!(confidence("receve", &unique, &uniform) >= 0.8);
+        assert!(confidence("zat", &tied, &uniform) < 0.5);
+        assert_eq!(confidence("xyz", &[], &uniform), 0.0);
+
+        // A far more common top suggestion is not a coin flip
+        let weighted = dictionary(&[("cat", 1000), ("bat", 1)]);
+        assert!(confidence("zat", &tied, &weighted) > 0.5);
     }
 
     #[test]
@@ -0,0 +1,111 @@
+use aho_corasick::{AhoCorasick, MatchKind};
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+
+/// A forbidden term found in content
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TermMatch {
+    /// Byte range of the match
+    pub start: usize,
+    pub end: usize,
+    /// Preferred replacement, if one is configured
+    pub preferred: Option<String>,
+}
+
+/// Matches forbidden words and phrases in a single pass
+pub struct TermMatcher {
+    automaton: AhoCorasick,
+    preferred: Vec<Option<String>>,
+}
+
+impl TermMatcher {
+    /// Build a matcher from a forbidden term → preferred replacement map (empty = no replacement)
+    pub fn new(terms: &HashMap<String, String>) -> Result<Option<Self>> {
+        if terms.is_empty() {
+            return Ok(None);
+        }
+
+        let mut entries: Vec<(&String, &String)> = terms.iter().collect();
+        entries.sort();
+
+        let automaton = AhoCorasick::builder()
+            .ascii_case_insensitive(true)
+            .match_kind(MatchKind::LeftmostLongest)
+            .build(entries.iter().map(|(term, _)| term.as_str()))
+            .context("Failed to build terminology matcher")?;
+        let preferred = entries
+            .iter()
+            .map(|(_, preferred)| Some(preferred.to_string()).filter(|p| !p.is_empty()))
+            .collect();
+
+        Ok(Some(Self {
+            automaton,
+            preferred,
+        }))
+    }
+
+    /// Find whole-word occurrences of forbidden terms
+    pub fn find(&self, content: &str) -> Vec<TermMatch> {
+        self.automaton
+            .find_iter(content)
+            .filter(|m| is_word_boundary(content, m.start(), m.end()))
+            .map(|m| TermMatch {
+                start: m.start(),
+                end: m.end(),
+                preferred: self.preferred[m.pattern().as_usize()].clone(),
+            })
+            .collect()
+    }
+}
+
+fn is_word_boundary(content: &str, start: usize, end: usize) -> bool {
+    let before = content[..start].chars().next_back();
+    let after = content[end..].chars().next();
+    !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matcher(terms: &[(&str, &str)]) -> TermMatcher {
+        let terms = terms
+            .iter()
+            .map(|(term, preferred)| (term.to_string(), preferred.to_string()))
+            .collect();
+        TermMatcher::new(&terms).unwrap().unwrap()
+    }
+
+    #[test]
+    fn test_words_and_phrases() {
+        let matcher = matcher(&[
+            ("whitelist", "allowlist"),
+            ("e-mail", "email"),
+            ("master node", "primary node"),
+            ("master", ""),
+        ]);
+        let content = "Add to the Whitelist, send an e-mail to the master node or master.";
+
+        let matches = matcher.find(content);
+        let found: Vec<(&str, Option<&str>)> = matches
+            .iter()
+            .map(|m| (&content[m.start..m.end], m.preferred.as_deref()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("Whitelist", Some("allowlist")),
+                ("e-mail", Some("email")),
+                ("master node", Some("primary node")),
+                ("master", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_requires_word_boundaries() {
+        let matcher = matcher(&[("master", "main")]);
+        assert!(matcher.find("remastered mastery").is_empty());
+        assert!(TermMatcher::new(&HashMap::new()).unwrap().is_none());
+    }
+}
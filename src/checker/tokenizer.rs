@@ -31,6 +31,45 @@ pub fn split_compound_word(word: &str) -> Vec<String> {
     }
 }
 
+/// Split a compound word, keeping each piece's casing and byte offset within the word
+///
+/// Runs of capitals stay together, so "RECIEVE" is one piece and "HTTPServer" splits
+/// into "HTTP" and "Server".
+pub fn split_compound_word_at(word: &str) -> Vec<(String, usize)> {
+    let mut result = Vec::new();
+    let mut current = String::new();
+    let mut current_start = 0;
+    let chars: Vec<(usize, char)> = word.char_indices().collect();
+
+    for (index, &(i, ch)) in chars.iter().enumerate() {
+        if ch == '_' || ch == '-' {
+            if !current.is_empty() {
+                result.push((std::mem::take(&mut current), current_start));
+            }
+            current_start = i + ch.len_utf8();
+        } else {
+            let previous = current.chars().next_back();
+            let next = chars.get(index + 1).map(|&(_, c)| c);
+            let boundary = ch.is_uppercase()
+                && previous
+                    .is_some_and(|p| !p.is_uppercase() || next.is_some_and(char::is_lowercase));
+            if boundary {
+                result.push((std::mem::take(&mut current), current_start));
+            }
+            if current.is_empty() {
+                current_start = i;
+            }
+            current.push(ch);
+        }
+    }
+
+    if !current.is_empty() {
+        result.push((current, current_start));
+    }
+
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -41,4 +80,24 @@ mod tests {
         assert_eq!(split_compound_word("snake_case"), vec!["snake", "case"]);
         assert_eq!(split_compound_word("kebab-case"), vec!["kebab", "case"]);
     }
+
+    #[test]
+    fn test_compound_splitting_offsets() {
+        assert_eq!(
+            split_compound_word_at("GitHub-flavored"),
+            vec![
+                ("Git".to_string(), 0),
+                ("Hub".to_string(), 3),
+                ("flavored".to_string(), 7)
+            ]
+        );
+        assert_eq!(
+            split_compound_word_at("RECIEVE"),
+            vec![("RECIEVE".to_string(), 0)]
+        );
+        assert_eq!(
+            split_compound_word_at("HTTPServer"),
+            vec![("HTTP".to_string(), 0), ("Server".to_string(), 4)]
+        );
+    }
 }
@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+
+/// American and British spellings, in `american->british` or one-way `american<-british`
+/// format
+const SPELLING_VARIANTS: &str = include_str!("variants.txt");
+
+/// Endings that differ between the two spellings, e.g. "realize" / "realise"
+const SUFFIXES: &[(&str, &str)] = &[
+    ("ize", "ise"),
+    ("izes", "ises"),
+    ("ized", "ised"),
+    ("izing", "ising"),
+    ("izer", "iser"),
+    ("izers", "isers"),
+    ("ization", "isation"),
+    ("izations", "isations"),
+    ("yze", "yse"),
+    ("yzes", "yses"),
+    ("yzed", "ysed"),
+    ("yzing", "ysing"),
+];
+
+/// "-ize" words spelled the same on both sides
+const INVARIANT_IZE: &[&str] = &[
+    "capsize", "downsize", "maize", "oversize", "prize", "resize", "seize", "size",
+];
+
+/// "-ise" words spelled the same on both sides
+const INVARIANT_ISE: &[&str] = &[
+    "advertise",
+    "advise",
+    "anise",
+    "apprise",
+    "arise",
+    "bruise",
+    "chastise",
+    "circumcise",
+    "comprise",
+    "compromise",
+    "concise",
+    "cruise",
+    "demise",
+    "despise",
+    "devise",
+    "disguise",
+    "enterprise",
+    "excise",
+    "exercise",
+    "expertise",
+    "franchise",
+    "improvise",
+    "incise",
+    "merchandise",
+    "noise",
+    "otherwise",
+    "paradise",
+    "poise",
+    "praise",
+    "precise",
+    "premise",
+    "promise",
+    "raise",
+    "reprise",
+    "revise",
+    "rise",
+    "supervise",
+    "surmise",
+    "surprise",
+    "televise",
+    "treatise",
+    "wise",
+];
+
+/// Which side of the Atlantic a spelling belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Variant {
+    American,
+    British,
+}
+
+/// Maps between American and British spellings of the same word
+pub struct Variants {
+    to_british: HashMap<String, String>,
+    to_american: HashMap<String, String>,
+}
+
+impl Default for Variants {
+    fn default() -> Self {
+        let mut to_british = HashMap::new();
+        let mut to_american = HashMap::new();
+        for line in SPELLING_VARIANTS
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+        {
+            if let Some((american, british)) = line.split_once("->") {
+                let (american, british) = (american.trim(), british.trim());
+                to_british.insert(american.to_string(), british.to_string());
+                to_american.insert(british.to_string(), american.to_string());
+            } else if let Some((american, british)) = line.split_once("<-") {
+                to_american.insert(british.trim().to_string(), american.trim().to_string());
+            }
+        }
+
+        Self {
+            to_british,
+            to_american,
+        }
+    }
+}
+
+impl Variants {
+    /// The variant a lowercased word is spelled in, and its spelling in the other one.
+    /// Words matched only by their ending must have a counterpart `is_known` accepts.
+    pub fn classify(
+        &self,
+        word: &str,
+        is_known: &dyn Fn(&str) -> bool,
+    ) -> Option<(Variant, String)> {
+        if let Some(british) = self.to_british.get(word) {
+            return Some((Variant::American, british.clone()));
+        }
+        if let Some(american) = self.to_american.get(word) {
+            return Some((Variant::British, american.clone()));
+        }
+
+        for (american, british) in SUFFIXES {
+            if let Some(stem) = word.strip_suffix(american) {
+                let base = format!("{}{}e", stem, &american[..2]);
+                let counterpart = format!("{}{}", stem, british);
+                if stem.len() >= 3
+                    && !INVARIANT_IZE.contains(&base.as_str())
+                    && is_known(&counterpart)
+                {
+                    return Some((Variant::American, counterpart));
+                }
+            }
+            // "analyses" is also the plural of "analysis"
+            if *british == "yses" {
+                continue;
+            }
+            if let Some(stem) = word.strip_suffix(british) {
+                let base = format!("{}{}e", stem, &british[..2]);
+                let counterpart = format!("{}{}", stem, american);
+                if stem.len() >= 3
+                    && !INVARIANT_ISE.contains(&base.as_str())
+                    && is_known(&counterpart)
+                {
+                    return Some((Variant::British, counterpart));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify() {
+        let variants = Variants::default();
+        let known =
+            |word: &str| ["realise", "realize", "analyses", "advize", "sise"].contains(&word);
+
+        assert_eq!(
+            variants.classify("color", &known),
+            Some((Variant::American, "colour".to_string()))
+        );
+        assert_eq!(
+            variants.classify("defences", &known),
+            Some((Variant::British, "defenses".to_string()))
+        );
+        assert_eq!(
+            variants.classify("realise", &known),
+            Some((Variant::British, "realize".to_string()))
+        );
+        assert_eq!(
+            variants.classify("analyzes", &known),
+            Some((Variant::American, "analyses".to_string()))
+        );
+
+        // Endings alone need a known counterpart and a word that varies
+        assert_eq!(variants.classify("analyses", &known), None);
+        assert_eq!(variants.classify("organize", &known), None);
+        assert_eq!(variants.classify("advise", &known), None);
+        assert_eq!(variants.classify("size", &known), None);
+        assert_eq!(variants.classify("table", &known), None);
+
+        // "license" is also British, so only "licence" has a side
+        assert_eq!(variants.classify("license", &known), None);
+        assert_eq!(
+            variants.classify("licences", &known),
+            Some((Variant::British, "licenses".to_string()))
+        );
+    }
+}
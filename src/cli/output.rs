@@ -1,4 +1,9 @@
-use crate::CheckResult;
+use crate::baseline::BaselineEntry;
+use crate::checker::layers::Verdict;
+use crate::checker::rules::RuleStatus;
+use crate::checker::Explanation;
+use crate::consistency::TermGroup;
+use crate::{CheckResult, ErrorKind, Severity};
 use colored::*;
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -38,7 +43,10 @@ struct JsonError {
     line: usize,
     column: usize,
     word: String,
+    kind: String,
+    severity: String,
     suggestions: Vec<String>,
+    confidence: f64,
     context: String,
 }
 
@@ -46,6 +54,7 @@ struct JsonError {
 struct JsonOutput {
     files_checked: usize,
     total_errors: usize,
+    total_warnings: usize,
     errors: Vec<JsonError>,
 }
 
@@ -76,12 +85,24 @@ fn print_text_errors(file_path: &Path, result: &CheckResult, colored_output: boo
 
     for error in &result.errors {
         let line_info = format!("{}:{}", error.line, error.column);
+        // Misspelling errors are the default finding; anything else is labelled
+        let kind = match (error.kind, error.severity) {
+            (ErrorKind::Misspelling, Severity::Error) => String::new(),
+            (kind, Severity::Error) => format!("[{}] ", kind.as_str()),
+            (kind, severity) => format!("[{} {}] ", kind.as_str(), severity.as_str()),
+        };
 
         if colored_output {
+            let word = match error.severity {
+                Severity::Error => error.word.red().bold(),
+                Severity::Warning => error.word.yellow().bold(),
+                Severity::Hint => error.word.cyan(),
+            };
             println!(
-                "  {} {} {}",
+                "  {} {}{} {}",
                 line_info.blue().bold(),
-                error.word.red().bold(),
+                kind.yellow(),
+                word,
                 format_context(&error.context, &error.word, colored_output)
             );
 
@@ -96,7 +117,7 @@ fn print_text_errors(file_path: &Path, result: &CheckResult, colored_output: boo
                 println!("    {} {}", "→".dimmed(), suggestions);
             }
         } else {
-            println!("  {} {} {}", line_info, error.word, &error.context);
+            println!("  {} {}{} {}", line_info, kind, error.word, &error.context);
 
             if !error.suggestions.is_empty() {
                 let suggestions = error
@@ -121,7 +142,10 @@ fn print_json_errors(file_path: &Path, result: &CheckResult) {
             line: e.line,
             column: e.column,
             word: e.word.clone(),
+            kind: e.kind.as_str().to_string(),
+            severity: e.severity.as_str().to_string(),
             suggestions: e.suggestions.clone(),
+            confidence: e.confidence,
             context: e.context.clone(),
         })
         .collect();
@@ -129,6 +153,7 @@ fn print_json_errors(file_path: &Path, result: &CheckResult) {
     let output = JsonOutput {
         files_checked: 1,
         total_errors: result.error_count,
+        total_warnings: result.warning_count,
         errors: json_errors,
     };
 
@@ -143,32 +168,49 @@ fn format_context(context: &str, word: &str, colored: bool) -> String {
     }
 }
 
-pub fn print_check_summary(total_errors: usize, files: &[impl AsRef<Path>], colored: bool) {
+pub fn print_check_summary(
+    total_errors: usize,
+    total_warnings: usize,
+    files: &[impl AsRef<Path>],
+    colored: bool,
+) {
+    let warnings = match total_warnings {
+        0 => String::new(),
+        1 => " (1 warning)".to_string(),
+        n => format!(" ({} warnings)", n),
+    };
+
     println!();
     if total_errors == 0 {
         if colored {
-            println!("{}", "✓ No spelling errors found!".green().bold());
+            println!(
+                "{}{}",
+                "✓ No spelling errors found!".green().bold(),
+                warnings.yellow()
+            );
         } else {
-            println!("✓ No spelling errors found!");
+            println!("✓ No spelling errors found!{}", warnings);
         }
     } else {
         let error_word = if total_errors == 1 { "error" } else { "errors" };
         if colored {
             println!(
-                "{} {} {} found in {} {}",
+                "{} {} {} found in {} {}{}",
                 "✗".red().bold(),
                 total_errors.to_string().red().bold(),
                 error_word,
                 files.len(),
-                if files.len() == 1 { "file" } else { "files" }
+                if files.len() == 1 { "file" } else { "files" },
+                warnings.yellow()
             );
         } else {
             println!(
-                "✗ {} {} found in {} {}",
+                "✗ {} {} found in {} {}{}",
                 total_errors,
                 error_word,
                 files.len(),
-                if files.len() == 1 { "file" } else { "files" }
+                if files.len() == 1 { "file" } else { "files" },
+                warnings
             );
         }
     }
@@ -269,3 +311,199 @@ pub fn print_interactive_prompt(
         }
     }
 }
+
+pub fn print_stale_baseline(entries: &[BaselineEntry], colored: bool) {
+    if entries.is_empty() {
+        return;
+    }
+
+    let entry_word = if entries.len() == 1 {
+        "entry"
+    } else {
+        "entries"
+    };
+
+    println!();
+    if colored {
+        println!(
+            "{} {} stale baseline {} (no longer found):",
+            "!".yellow().bold(),
+            entries.len().to_string().yellow().bold(),
+            entry_word
+        );
+    } else {
+        println!(
+            "! {} stale baseline {} (no longer found):",
+            entries.len(),
+            entry_word
+        );
+    }
+
+    for entry in entries {
+        if colored {
+            println!("  {} {}", entry.file.dimmed(), entry.word.yellow());
+        } else {
+            println!("  {} {}", entry.file, entry.word);
+        }
+    }
+
+    if colored {
+        println!(
+            "Run {} to refresh the baseline.",
+            "spellchk baseline create".cyan()
+        );
+    } else {
+        println!("Run spellchk baseline create to refresh the baseline.");
+    }
+}
+
+pub fn print_explanation(explanation: &Explanation, colored: bool) {
+    let (main, layers) = explanation
+        .layers
+        .split_last()
+        .expect("explanation always includes the main dictionary");
+
+    // Mirror the checker's order: layers, then ignore patterns, then the main dictionary
+    let deciding = layers.iter().find(|(_, verdict)| verdict.is_some());
+    let decision = match deciding {
+        None if !explanation.corrections.is_empty() && !explanation.ignored => format!(
+            "known misspelling of {}",
+            explanation.corrections.join(" or ")
+        ),
+        _ if explanation.miscapitalized => format!(
+            "wrong capitalization (expected {})",
+            explanation.canonical.join(" or ")
+        ),
+        Some((name, Some(Verdict::Rejected))) => format!("rejected by '{}'", name),
+        Some((name, _)) => format!("accepted by '{}'", name),
+        None if explanation.ignored => "ignored (matches an ignore pattern)".to_string(),
+        None if main.1.is_some() => format!("accepted by '{}'", main.0),
+        None => "not found in any dictionary".to_string(),
+    };
+
+    if colored {
+        let word = if explanation.misspelled {
+            explanation.word.red().bold()
+        } else {
+            explanation.word.green().bold()
+        };
+        println!("{}: {}", word, decision);
+    } else {
+        println!("{}: {}", explanation.word, decision);
+    }
+
+    let width = explanation
+        .layers
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or(0);
+    for (name, verdict) in &explanation.layers {
+        let verdict = match verdict {
+            Some(Verdict::Accepted) if colored => "accepted".green().to_string(),
+            Some(Verdict::Rejected) if colored => "rejected".red().to_string(),
+            Some(Verdict::Accepted) => "accepted".to_string(),
+            Some(Verdict::Rejected) => "rejected".to_string(),
+            None if colored => "-".dimmed().to_string(),
+            None => "-".to_string(),
+        };
+        println!("  {:<width$}  {}", name, verdict, width = width);
+    }
+}
+
+pub fn print_rules(rules: &[RuleStatus], colored: bool) {
+    let width = rules.iter().map(|rule| rule.id.len()).max().unwrap_or(0);
+
+    for rule in rules {
+        let state = if rule.enabled { "enabled" } else { "disabled" };
+        let state = format!("{:<8}", state);
+        let severity = format!("{:<7}", rule.severity.as_str());
+        if colored {
+            let state = if rule.enabled {
+                state.green()
+            } else {
+                state.dimmed()
+            };
+            println!(
+                "  {:<width$}  {}  {}  {}",
+                rule.id.bold(),
+                state,
+                severity.yellow(),
+                rule.description,
+                width = width
+            );
+        } else {
+            println!(
+                "  {:<width$}  {}  {}  {}",
+                rule.id,
+                state,
+                severity,
+                rule.description,
+                width = width
+            );
+        }
+    }
+}
+
+/// Locations listed per spelling before the rest are summarized
+const MAX_LOCATIONS: usize = 5;
+
+pub fn print_term_groups(groups: &[TermGroup], colored: bool) {
+    if groups.is_empty() {
+        if colored {
+            println!("{}", "✓ Terms are spelled consistently".green().bold());
+        } else {
+            println!("✓ Terms are spelled consistently");
+        }
+        return;
+    }
+
+    for group in groups {
+        let heading = format!(
+            "{}: {} spellings, {} uses",
+            group.majority(),
+            group.forms.len(),
+            group.total()
+        );
+        if colored {
+            println!("\n{}", heading.bold());
+        } else {
+            println!("\n{}", heading);
+        }
+
+        let width = group
+            .forms
+            .iter()
+            .map(|(form, _)| form.chars().count())
+            .max()
+            .unwrap_or(0);
+        for (index, (form, seen)) in group.forms.iter().enumerate() {
+            let mut locations: Vec<String> = seen
+                .iter()
+                .take(MAX_LOCATIONS)
+                .map(|o| format!("{}:{}:{}", o.file.display(), o.line, o.column))
+                .collect();
+            if seen.len() > MAX_LOCATIONS {
+                locations.push(format!("and {} more", seen.len() - MAX_LOCATIONS));
+            }
+
+            let form = format!("{:<width$}", form, width = width);
+            let count = format!("{:>3}", seen.len());
+            if colored {
+                let form = if index == 0 {
+                    form.green()
+                } else {
+                    form.yellow()
+                };
+                println!(
+                    "  {}  {}  {}",
+                    form,
+                    count.bold(),
+                    locations.join(", ").dimmed()
+                );
+            } else {
+                println!("  {}  {}  {}", form, count, locations.join(", "));
+            }
+        }
+    }
+}
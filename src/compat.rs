@@ -0,0 +1,253 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+
+/// cspell config file names, in the order cspell itself looks for them
+const CSPELL_FILES: &[&str] = &[
+    ".cspell.json",
+    "cspell.json",
+    ".cSpell.json",
+    "cSpell.json",
+    "cspell.config.json",
+];
+
+const CODESPELL_FILES: &[&str] = &[".codespellrc", "setup.cfg"];
+
+/// Settings imported from another spellchecker's configuration
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CompatConfig {
+    pub language: Option<String>,
+    pub words: Vec<String>,
+    pub ignore_paths: Vec<String>,
+    pub ignore_patterns: Vec<String>,
+}
+
+impl CompatConfig {
+    fn extend(&mut self, other: CompatConfig) {
+        if self.language.is_none() {
+            self.language = other.language;
+        }
+        self.words.extend(other.words);
+        self.ignore_paths.extend(other.ignore_paths);
+        self.ignore_patterns.extend(other.ignore_patterns);
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct CspellConfig {
+    language: Option<String>,
+    words: Vec<String>,
+    ignore_words: Vec<String>,
+    ignore_paths: Vec<String>,
+    ignore_reg_exp_list: Vec<String>,
+}
+
+/// Load cspell and codespell configuration files found in `dir`
+pub fn load(dir: &Path) -> Result<CompatConfig> {
+    let mut config = CompatConfig::default();
+
+    if let Some(path) = CSPELL_FILES
+        .iter()
+        .map(|f| dir.join(f))
+        .find(|p| p.exists())
+    {
+        config.extend(load_cspell(&path)?);
+    }
+
+    for path in CODESPELL_FILES.iter().map(|f| dir.join(f)) {
+        if path.exists() {
+            config.extend(load_codespell(&path)?);
+        }
+    }
+
+    Ok(config)
+}
+
+fn load_cspell(path: &Path) -> Result<CompatConfig> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read config file: {}", path.display()))?;
+    let cspell: CspellConfig = serde_json::from_str(&strip_json_comments(&contents))
+        .with_context(|| format!("Failed to parse config file: {}", path.display()))?;
+
+    let mut words = cspell.words;
+    words.extend(cspell.ignore_words);
+
+    Ok(CompatConfig {
+        language: cspell.language.as_deref().and_then(cspell_language),
+        words,
+        ignore_paths: cspell.ignore_paths,
+        ignore_patterns: cspell
+            .ignore_reg_exp_list
+            .iter()
+            .filter_map(|p| cspell_regex(p))
+            .collect(),
+    })
+}
+
+fn load_codespell(path: &Path) -> Result<CompatConfig> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read config file: {}", path.display()))?;
+
+    let mut config = CompatConfig::default();
+    let mut in_section = false;
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            in_section = line == "[codespell]";
+            continue;
+        }
+        if !in_section || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let list = || {
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        };
+
+        match key.trim() {
+            "skip" => config.ignore_paths.extend(list()),
+            "ignore-words-list" => config.words.extend(list()),
+            "ignore-regex" => config.ignore_patterns.push(value.trim().to_string()),
+            _ => {}
+        }
+    }
+
+    Ok(config)
+}
+
+/// Map cspell locales ("en", "en-GB", "en,fr") to dictionary names
+fn cspell_language(language: &str) -> Option<String> {
+    let first = language.split(',').next()?.trim();
+    match first {
+        "" => None,
+        "en" | "en-US" | "en_US" => Some("en_US".to_string()),
+        other => Some(other.replace('-', "_")),
+    }
+}
+
+/// Convert a cspell `/pattern/flags` regex; named patterns are not supported
+fn cspell_regex(pattern: &str) -> Option<String> {
+    let body = pattern.strip_prefix('/')?;
+    let end = body.rfind('/')?;
+    let (regex, flags) = (&body[..end], &body[end + 1..]);
+
+    if flags.contains('i') {
+        Some(format!("(?i){}", regex))
+    } else {
+        Some(regex.to_string())
+    }
+}
+
+/// Remove `//` and `/* */` comments (allowed by cspell) outside of strings
+fn strip_json_comments(json: &str) -> String {
+    let mut out = String::with_capacity(json.len());
+    let mut chars = json.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(ch) = chars.next() {
+        if in_string {
+            out.push(ch);
+            if ch == '\\' {
+                if let Some(next) = chars.next() {
+                    out.push(next);
+                }
+            } else if ch == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match (ch, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                out.push(ch);
+            }
+            ('/', Some('/')) => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                        break;
+                    }
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut prev = ' ';
+                for c in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            _ => out.push(ch),
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_load_cspell() {
+        let dir = tempdir().unwrap();
+        fs::write(
+            dir.path().join("cspell.json"),
+            r#"{
+  // Project words
+  "version": "0.2",
+  "language": "en-GB",
+  "words": ["kubectl"],
+  "ignoreWords": ["etcd"],
+  "ignorePaths": ["node_modules/**", "*.lock"],
+  "ignoreRegExpList": ["/0x[0-9a-f]+/i", "Urls"]
+}"#,
+        )
+        .unwrap();
+
+        let config = load(dir.path()).unwrap();
+        assert_eq!(config.language.as_deref(), Some("en_GB"));
+        assert_eq!(config.words, vec!["kubectl", "etcd"]);
+        assert_eq!(config.ignore_paths, vec!["node_modules/**", "*.lock"]);
+        assert_eq!(config.ignore_patterns, vec!["(?i)0x[0-9a-f]+"]);
+    }
+
+    #[test]
+    fn test_load_codespell() {
+        let dir = tempdir().unwrap();
+        fs::write(
+            dir.path().join(".codespellrc"),
+            "[codespell]\nskip = ./build,*.svg\nignore-words-list = crate,nd\n",
+        )
+        .unwrap();
+
+        let config = load(dir.path()).unwrap();
+        assert_eq!(config.words, vec!["crate", "nd"]);
+        assert_eq!(config.ignore_paths, vec!["./build", "*.svg"]);
+        assert_eq!(config.language, None);
+    }
+
+    #[test]
+    fn test_strip_json_comments_keeps_strings() {
+        let json = r#"{"url": "https://example.com/*x*/", /* note */ "a": 1}"#;
+        assert_eq!(
+            strip_json_comments(json),
+            r#"{"url": "https://example.com/*x*/",  "a": 1}"#
+        );
+    }
+}
@@ -1,6 +1,11 @@
+use crate::checker::keyboard::KeyboardLayout;
+use crate::dict::sources::{builtin_sources, DictionarySource};
+use crate::Severity;
 use anyhow::{Context, Result};
 use directories::ProjectDirs;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -10,20 +15,99 @@ pub struct Config {
     pub personal_dictionary: Option<PathBuf>,
     pub ignore_patterns: Vec<String>,
 
+    /// Rule ids to run; `None` when a config file leaves the list out
     #[serde(default)]
-    pub enabled_rules: Vec<String>,
+    pub enabled_rules: Option<Vec<String>>,
 
     #[serde(default = "default_max_suggestions")]
     pub max_suggestions: usize,
 
     #[serde(default)]
     pub case_sensitive: bool,
+
+    /// Extra words accepted in this project
+    #[serde(default)]
+    pub words: Vec<String>,
+
+    /// Gitignore-style globs of files to skip
+    #[serde(default)]
+    pub ignore_paths: Vec<String>,
+
+    /// Also read cspell.json and .codespellrc from the working directory (off when unset)
+    #[serde(default)]
+    pub compat_configs: Option<bool>,
+
+    /// Per-language dictionary sources, overriding the built-in ones
+    #[serde(default)]
+    pub sources: HashMap<String, DictionarySource>,
+
+    /// Word-list layers consulted in order before the main dictionary
+    #[serde(default)]
+    pub dictionaries: Vec<DictionaryLayer>,
+
+    /// Bundled vocabulary packs to enable (e.g., "rust", "devops")
+    #[serde(default)]
+    pub packs: Vec<String>,
+
+    /// Forbidden words and phrases mapped to their preferred replacement ("" for none)
+    #[serde(default)]
+    pub terms: HashMap<String, String>,
+
+    /// Extra typo → correction pairs consulted before the dictionary
+    #[serde(default)]
+    pub misspellings: HashMap<String, String>,
+
+    /// Minimum confidence for `--fix` to apply a suggested correction
+    #[serde(default = "default_fix_min_confidence")]
+    pub fix_min_confidence: f64,
+
+    /// Severity of forbidden-term findings
+    #[serde(default = "default_terms_severity")]
+    pub terms_severity: Severity,
+
+    /// Keyboard layout used to weigh typing slips when ranking suggestions
+    #[serde(default)]
+    pub keyboard_layout: KeyboardLayout,
+
+    /// Per-rule settings, keyed by rule id
+    #[serde(default)]
+    pub rules: HashMap<String, RuleConfig>,
+}
+
+/// Settings from a `[rules.<id>]` table
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct RuleConfig {
+    /// Overrides the rule's default severity
+    pub severity: Option<Severity>,
+
+    /// Rule-specific options
+    #[serde(flatten)]
+    pub options: toml::Table,
+}
+
+/// A named word list in the dictionary stack
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DictionaryLayer {
+    pub name: String,
+    pub path: PathBuf,
+
+    /// Compile the list to a cached FST instead of loading it into memory
+    #[serde(default)]
+    pub compile: bool,
 }
 
 fn default_max_suggestions() -> usize {
     5
 }
 
+fn default_fix_min_confidence() -> f64 {
+    0.8
+}
+
+fn default_terms_severity() -> Severity {
+    Severity::Warning
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
@@ -35,9 +119,27 @@ impl Default for Config {
                 r"\b[a-fA-F0-9]{32,}\b".to_string(), // Hashes
                 r"[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}".to_string(), // Emails
             ],
-            enabled_rules: vec!["check-compound".to_string(), "check-rare".to_string()],
+            enabled_rules: Some(vec![
+                "check-compound".to_string(),
+                "check-rare".to_string(),
+                "repeated-word".to_string(),
+                "article-agreement".to_string(),
+                "spelling-variant".to_string(),
+            ]),
             max_suggestions: 5,
             case_sensitive: false,
+            words: Vec::new(),
+            ignore_paths: Vec::new(),
+            compat_configs: None,
+            sources: HashMap::new(),
+            dictionaries: Vec::new(),
+            packs: Vec::new(),
+            misspellings: HashMap::new(),
+            fix_min_confidence: default_fix_min_confidence(),
+            terms: HashMap::new(),
+            terms_severity: default_terms_severity(),
+            keyboard_layout: KeyboardLayout::default(),
+            rules: HashMap::new(),
         }
     }
 }
@@ -45,17 +147,20 @@ impl Default for Config {
 impl Config {
     /// Load configuration with priority: CLI args > local config > global config > defaults
     pub fn load(
-        language: String,
+        language: Option<String>,
         personal_dict: Option<PathBuf>,
         cli_patterns: Vec<String>,
     ) -> Result<Self> {
         let mut config = Self::default();
+        // `language` is required in config files, so loading one sets it
+        let mut language_set = false;
 
         // Load global config
         if let Some(global_path) = Self::global_config_path() {
             if global_path.exists() {
                 let global_config = Self::from_file(&global_path)?;
                 config = config.merge(global_config);
+                language_set = true;
             }
         }
 
@@ -64,10 +169,26 @@ impl Config {
         if local_path.exists() {
             let local_config = Self::from_file(&local_path)?;
             config = config.merge(local_config);
+            language_set = true;
+        }
+
+        // Import cspell/codespell settings (spellchk's own config takes precedence)
+        if config.compat_configs == Some(true) {
+            let compat = crate::compat::load(Path::new("."))?;
+            if let Some(compat_language) = compat.language {
+                if !language_set {
+                    config.language = compat_language;
+                }
+            }
+            config.words.extend(compat.words);
+            config.ignore_paths.extend(compat.ignore_paths);
+            config.ignore_patterns.extend(compat.ignore_patterns);
         }
 
         // Apply CLI overrides
-        config.language = language;
+        if let Some(language) = language {
+            config.language = language;
+        }
         if let Some(dict) = personal_dict {
             config.personal_dictionary = Some(dict);
         }
@@ -97,8 +218,18 @@ impl Config {
     fn from_file(path: &Path) -> Result<Self> {
         let contents = fs::read_to_string(path)
             .with_context(|| format!("Failed to read config file: {}", path.display()))?;
-        toml::from_str(&contents)
-            .with_context(|| format!("Failed to parse config file: {}", path.display()))
+        let mut config: Self = toml::from_str(&contents)
+            .with_context(|| format!("Failed to parse config file: {}", path.display()))?;
+
+        // Layer paths are relative to the config file that declares them
+        if let Some(dir) = path.parent() {
+            for layer in &mut config.dictionaries {
+                if layer.path.is_relative() {
+                    layer.path = dir.join(&layer.path);
+                }
+            }
+        }
+        Ok(config)
     }
 
     fn merge(mut self, other: Self) -> Self {
@@ -112,16 +243,86 @@ impl Config {
         if !other.ignore_patterns.is_empty() {
             self.ignore_patterns = other.ignore_patterns;
         }
-        if !other.enabled_rules.is_empty() {
+        // An explicit empty list turns every rule off
+        if other.enabled_rules.is_some() {
             self.enabled_rules = other.enabled_rules;
         }
         if other.max_suggestions != default_max_suggestions() {
             self.max_suggestions = other.max_suggestions;
         }
         self.case_sensitive = other.case_sensitive;
+        if !other.words.is_empty() {
+            self.words.extend(other.words);
+        }
+        if !other.ignore_paths.is_empty() {
+            self.ignore_paths = other.ignore_paths;
+        }
+        if other.compat_configs.is_some() {
+            self.compat_configs = other.compat_configs;
+        }
+        self.sources.extend(other.sources);
+        // Layers from the more specific config are consulted first
+        if !other.dictionaries.is_empty() {
+            self.dictionaries = other
+                .dictionaries
+                .into_iter()
+                .chain(self.dictionaries)
+                .collect();
+        }
+        self.misspellings.extend(other.misspellings);
+        if other.fix_min_confidence != default_fix_min_confidence() {
+            self.fix_min_confidence = other.fix_min_confidence;
+        }
+        self.terms.extend(other.terms);
+        if other.terms_severity != default_terms_severity() {
+            self.terms_severity = other.terms_severity;
+        }
+        if other.keyboard_layout != KeyboardLayout::default() {
+            self.keyboard_layout = other.keyboard_layout;
+        }
+        self.rules.extend(other.rules);
+        for pack in other.packs {
+            if !self.packs.contains(&pack) {
+                self.packs.push(pack);
+            }
+        }
         self
     }
 
+    /// Built-in dictionary sources with configured overrides applied
+    pub fn dictionary_sources(&self) -> HashMap<String, DictionarySource> {
+        let mut sources = builtin_sources();
+        sources.extend(self.sources.clone());
+        sources
+    }
+
+    /// Build a matcher for `ignore_paths`, relative to the working directory
+    pub fn ignore_path_matcher(&self) -> Result<Gitignore> {
+        let mut builder = GitignoreBuilder::new(".");
+        for pattern in &self.ignore_paths {
+            let pattern = pattern.strip_prefix("./").unwrap_or(pattern);
+            builder
+                .add_line(None, pattern)
+                .with_context(|| format!("Invalid ignore path: {}", pattern))?;
+        }
+        builder
+            .build()
+            .context("Failed to build ignore path matcher")
+    }
+
+    /// Check whether a file should be skipped according to `ignore_paths`
+    pub fn is_path_ignored(matcher: &Gitignore, path: &Path) -> bool {
+        // Absolute paths outside the working directory can't match relative globs
+        let path = match std::env::current_dir() {
+            Ok(cwd) if path.is_absolute() => match path.strip_prefix(&cwd) {
+                Ok(relative) => relative,
+                Err(_) => return false,
+            },
+            _ => path,
+        };
+        matcher.matched_path_or_any_parents(path, false).is_ignore()
+    }
+
     pub fn global_config_path() -> Option<PathBuf> {
         ProjectDirs::from("", "", "spellchk").map(|dirs| dirs.config_dir().join("config.toml"))
     }
@@ -142,6 +343,7 @@ impl Config {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::dict::sources::DictFormat;
 
     #[test]
     fn test_default_config() {
@@ -162,4 +364,151 @@ mod tests {
         let merged = base.merge(override_config);
         assert_eq!(merged.language, "en_GB");
     }
+
+    #[test]
+    fn test_dictionary_sources() {
+        let config: Config = toml::from_str(
+            r#"
+language = "de_DE"
+personal_dictionary = "words.txt"
+ignore_patterns = []
+
+[sources.de_DE]
+url = "https://mirror.internal/de_DE.dic"
+format = "hunspell"
+sha256 = "abc123"
+"#,
+        )
+        .unwrap();
+
+        let sources = config.dictionary_sources();
+        assert!(sources.contains_key("en_US"));
+        assert_eq!(sources["de_DE"].format, DictFormat::Hunspell);
+        assert_eq!(sources["de_DE"].sha256.as_deref(), Some("abc123"));
+    }
+
+    #[test]
+    fn test_merge_dictionary_layers() {
+        let team = DictionaryLayer {
+            name: "team".to_string(),
+            path: PathBuf::from("/shared/team.txt"),
+            compile: true,
+        };
+        let project = DictionaryLayer {
+            name: "project".to_string(),
+            path: PathBuf::from("words.txt"),
+            compile: false,
+        };
+        let global = Config {
+            dictionaries: vec![team.clone()],
+            ..Default::default()
+        };
+        let local = Config {
+            dictionaries: vec![project.clone()],
+            ..Default::default()
+        };
+
+        let merged = Config::default().merge(global).merge(local);
+        assert_eq!(merged.dictionaries, vec![project, team]);
+    }
+
+    #[test]
+    fn test_layer_paths_relative_to_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(
+            &path,
+            r#"
+language = "en_US"
+ignore_patterns = []
+
+[[dictionaries]]
+name = "personal"
+path = "words/personal.txt"
+
+[[dictionaries]]
+name = "team"
+path = "/shared/team.txt"
+"#,
+        )
+        .unwrap();
+
+        let config = Config::from_file(&path).unwrap();
+        assert_eq!(
+            config.dictionaries[0].path,
+            dir.path().join("words/personal.txt")
+        );
+        assert_eq!(
+            config.dictionaries[1].path,
+            PathBuf::from("/shared/team.txt")
+        );
+    }
+
+    #[test]
+    fn test_compat_configs_merge() {
+        let global = Config {
+            compat_configs: Some(false),
+            ..Default::default()
+        };
+        let local: Config = toml::from_str("language = \"en_US\"\nignore_patterns = []\n").unwrap();
+
+        // A local file without the key keeps the global setting
+        let merged = Config::default().merge(global).merge(local);
+        assert_eq!(merged.compat_configs, Some(false));
+        assert_eq!(Config::default().compat_configs, None);
+    }
+
+    #[test]
+    fn test_rule_tables() {
+        let config: Config = toml::from_str(
+            r#"
+language = "en_US"
+ignore_patterns = []
+
+[rules.check-compound]
+severity = "warning"
+"#,
+        )
+        .unwrap();
+
+        let merged = Config::default().merge(config);
+        assert_eq!(
+            merged.enabled_rules.unwrap(),
+            vec![
+                "check-compound",
+                "check-rare",
+                "repeated-word",
+                "article-agreement",
+                "spelling-variant"
+            ]
+        );
+        assert_eq!(
+            merged.rules["check-compound"].severity,
+            Some(Severity::Warning)
+        );
+        assert!(merged.rules["check-compound"].options.is_empty());
+
+        // An explicit empty list disables every rule
+        let config: Config =
+            toml::from_str("language = \"en_US\"\nignore_patterns = []\nenabled_rules = []\n")
+                .unwrap();
+        let merged = Config::default().merge(config);
+        assert_eq!(merged.enabled_rules, Some(Vec::new()));
+    }
+
+    #[test]
+    fn test_ignore_paths() {
+        let config = Config {
+            ignore_paths: vec!["./build".to_string(), "*.lock".to_string()],
+            ..Default::default()
+        };
+        let matcher = config.ignore_path_matcher().unwrap();
+
+        assert!(Config::is_path_ignored(
+            &matcher,
+            Path::new("build/out.txt")
+        ));
+        assert!(Config::is_path_ignored(&matcher, Path::new("./Cargo.lock")));
+        assert!(!Config::is_path_ignored(&matcher, Path::new("src/main.rs")));
+    }
 }
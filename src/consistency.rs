@@ -0,0 +1,333 @@
+use crate::parser::TextSpan;
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use toml_edit::{Array, DocumentMut, Item, Table};
+
+/// Where a spelling was seen
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Occurrence {
+    pub file: PathBuf,
+    pub line: usize,
+    pub column: usize,
+}
+
+/// The spellings of one term across the checked files
+#[derive(Debug, Clone)]
+pub struct TermGroup {
+    /// Lowercase with spaces and hyphens removed, e.g. "dataset"
+    pub key: String,
+    /// Each spelling with where it was seen, most used first
+    pub forms: Vec<(String, Vec<Occurrence>)>,
+}
+
+impl TermGroup {
+    /// The most used spelling
+    pub fn majority(&self) -> &str {
+        &self.forms[0].0
+    }
+
+    pub fn total(&self) -> usize {
+        self.forms.iter().map(|(_, seen)| seen.len()).sum()
+    }
+}
+
+/// Collects how terms are spelled across files
+#[derive(Default)]
+pub struct TermIndex {
+    groups: Vec<TermGroup>,
+    by_key: HashMap<String, usize>,
+}
+
+impl TermIndex {
+    /// Record the words of one file, including adjacent pairs written apart or
+    /// hyphenated ("data set", "data-set"). Pairs of two words that `is_known`
+    /// accepts on their own are only recorded hyphenated, since "every day" is a
+    /// phrase rather than another spelling of "everyday".
+    pub fn add(
+        &mut self,
+        file: &Path,
+        content: &str,
+        spans: &[TextSpan],
+        is_known: &dyn Fn(&str) -> bool,
+    ) {
+        let spans: Vec<&TextSpan> = spans
+            .iter()
+            .filter(|span| span.start < span.end && is_whole_word(content, span))
+            .collect();
+
+        for (index, span) in spans.iter().enumerate() {
+            self.record(file, content, span, span.end);
+
+            if let Some(next) = spans.get(index + 1) {
+                let between = content.get(span.end..next.start).unwrap_or("");
+                let phrase = between == " "
+                    && is_known(&span.text.to_lowercase())
+                    && is_known(&next.text.to_lowercase());
+                if next.line == span.line && (between == " " || between == "-") && !phrase {
+                    self.record(file, content, span, next.end);
+                }
+            }
+        }
+    }
+
+    fn record(&mut self, file: &Path, content: &str, first: &TextSpan, end: usize) {
+        let form = &content[first.start..end];
+
+        // Capitals at the start of a sentence or line say nothing about a term's casing,
+        // and neither do words shouted in capitals
+        let capitalized = form.chars().next().is_some_and(char::is_uppercase);
+        if (capitalized && starts_sentence(content, first.start))
+            || (form.chars().filter(|c| c.is_alphabetic()).count() > 1
+                && !form.chars().any(char::is_lowercase))
+        {
+            return;
+        }
+
+        let key: String = form
+            .chars()
+            .filter(|c| *c != ' ' && *c != '-')
+            .flat_map(char::to_lowercase)
+            .collect();
+        let index = *self.by_key.entry(key.clone()).or_insert_with(|| {
+            self.groups.push(TermGroup {
+                key,
+                forms: Vec::new(),
+            });
+            self.groups.len() - 1
+        });
+
+        let occurrence = Occurrence {
+            file: file.to_path_buf(),
+            line: first.line,
+            column: first.column,
+        };
+        let forms = &mut self.groups[index].forms;
+        match forms.iter_mut().find(|(existing, _)| existing == form) {
+            Some((_, seen)) => seen.push(occurrence),
+            None => forms.push((form.to_string(), vec![occurrence])),
+        }
+    }
+
+    /// Terms seen in more than one spelling, most used first
+    pub fn inconsistent(&self) -> Vec<TermGroup> {
+        let mut groups: Vec<TermGroup> = self
+            .groups
+            .iter()
+            // Pairs of words only matter as another spelling of a single word
+            .filter(|group| {
+                group.forms.len() > 1 && group.forms.iter().any(|(form, _)| !form.contains(' '))
+            })
+            .cloned()
+            .collect();
+
+        for group in &mut groups {
+            // Ties go to a joined spelling, then to the one seen first
+            group.forms.sort_by_key(|(form, seen)| {
+                (std::cmp::Reverse(seen.len()), form.contains([' ', '-']))
+            });
+        }
+        groups.sort_by(|a, b| b.total().cmp(&a.total()).then_with(|| a.key.cmp(&b.key)));
+        groups
+    }
+}
+
+/// What `promote` added to the config
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Promotion {
+    /// Other spellings mapped to the majority form in `[terms]`
+    pub terms: usize,
+    /// Majority casings added to `words`
+    pub words: usize,
+}
+
+/// Record each group's majority form in a config file: other spellings become
+/// preferred terms, and a capitalized majority casing becomes a listed word
+pub fn promote(config_path: &Path, groups: &[TermGroup]) -> Result<Promotion> {
+    let contents = if config_path.exists() {
+        fs::read_to_string(config_path)
+            .with_context(|| format!("Failed to read config file: {}", config_path.display()))?
+    } else {
+        String::new()
+    };
+    let mut document: DocumentMut = contents
+        .parse()
+        .with_context(|| format!("Failed to parse config file: {}", config_path.display()))?;
+
+    let mut promotion = Promotion::default();
+    let mut terms = Vec::new();
+    let mut words = Vec::new();
+
+    for group in groups {
+        let majority = group.majority();
+        for (form, _) in &group.forms[1..] {
+            if form.to_lowercase() != majority.to_lowercase() {
+                terms.push((form.to_lowercase(), majority.to_string()));
+            } else if majority.chars().any(char::is_uppercase) && !words.contains(&majority) {
+                words.push(majority);
+            }
+        }
+    }
+
+    if !terms.is_empty() {
+        let table = document
+            .entry("terms")
+            .or_insert_with(|| Item::Table(Table::new()))
+            .as_table_like_mut()
+            .context("`terms` in config is not a table")?;
+        for (term, preferred) in terms {
+            if !table.contains_key(&term) {
+                table.insert(&term, toml_edit::value(preferred));
+                promotion.terms += 1;
+            }
+        }
+    }
+
+    if !words.is_empty() {
+        let listed = document
+            .entry("words")
+            .or_insert_with(|| Item::Value(Array::new().into()))
+            .as_array_mut()
+            .context("`words` in config is not an array")?;
+        for word in words {
+            if !listed.iter().any(|listed| listed.as_str() == Some(word)) {
+                listed.push(word);
+                promotion.words += 1;
+            }
+        }
+    }
+
+    if promotion != Promotion::default() {
+        fs::write(config_path, document.to_string())
+            .with_context(|| format!("Failed to write config file: {}", config_path.display()))?;
+    }
+
+    Ok(promotion)
+}
+
+/// Whether a span is a word of its own rather than part of an identifier like `dataSet`
+fn is_whole_word(content: &str, span: &TextSpan) -> bool {
+    let joined = |c: char| c.is_alphanumeric() || c == '_';
+    !content[..span.start]
+        .chars()
+        .next_back()
+        .is_some_and(joined)
+        && !content[span.end..].chars().next().is_some_and(joined)
+}
+
+/// Whether only punctuation other than a comma or semicolon precedes an offset on its line
+fn starts_sentence(content: &str, offset: usize) -> bool {
+    let line = content[..offset].rsplit('\n').next().unwrap_or("");
+    match line.trim_end().chars().next_back() {
+        None => true,
+        Some(previous) => !previous.is_alphanumeric() && !matches!(previous, ',' | ';'),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{parse_content, FileType};
+
+    fn index(files: &[(&str, &str)]) -> TermIndex {
+        let known = |word: &str| ["every", "day", "everyday", "may", "be"].contains(&word);
+        let mut index = TermIndex::default();
+        for (name, content) in files {
+            let file_type = FileType::from_path(Path::new(name));
+            let spans = parse_content(file_type, content).unwrap();
+            index.add(Path::new(name), content, &spans, &known);
+        }
+        index
+    }
+
+    fn forms(group: &TermGroup) -> Vec<(&str, usize)> {
+        group
+            .forms
+            .iter()
+            .map(|(form, seen)| (form.as_str(), seen.len()))
+            .collect()
+    }
+
+    #[test]
+    fn test_inconsistent_terms() {
+        let index = index(&[
+            ("a.md", "Load the dataset, then the data-set.\n"),
+            (
+                "b.txt",
+                "Split the data set and the dataset.\nDeploy to kubernetes.\n",
+            ),
+            (
+                "c.md",
+                "We run Kubernetes, via Kubernetes on `dataSet`.\n\nKubernetes is fine.\n",
+            ),
+        ]);
+        let groups = index.inconsistent();
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].key, "dataset");
+        assert_eq!(
+            forms(&groups[0]),
+            vec![("dataset", 2), ("data-set", 1), ("data set", 1)]
+        );
+        assert_eq!(
+            groups[0].forms[2].1,
+            vec![Occurrence {
+                file: PathBuf::from("b.txt"),
+                line: 1,
+                column: 11
+            }]
+        );
+
+        // Sentence-initial capitals don't count towards a casing
+        assert_eq!(groups[1].key, "kubernetes");
+        assert_eq!(
+            forms(&groups[1]),
+            vec![("Kubernetes", 2), ("kubernetes", 1)]
+        );
+    }
+
+    #[test]
+    fn test_phrases_are_not_spellings() {
+        let index = index(&[(
+            "a.txt",
+            "We ship every day, an everyday task. It may be done, maybe not.\nAn every-day chore.",
+        )]);
+        let groups = index.inconsistent();
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(forms(&groups[0]), vec![("everyday", 1), ("every-day", 1)]);
+    }
+
+    #[test]
+    fn test_promote() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config_path = temp_dir.path().join(".spellchk.toml");
+        fs::write(
+            &config_path,
+            "# Project config\nlanguage = \"en_US\"\nignore_patterns = []\n\n[terms]\nwhitelist = \"allowlist\"\n",
+        )
+        .unwrap();
+
+        let groups = index(&[(
+            "a.txt",
+            "the dataset, the dataset, the data set, via Kubernetes, on Kubernetes, at kubernetes",
+        )])
+        .inconsistent();
+        let promotion = promote(&config_path, &groups).unwrap();
+        assert_eq!(promotion, Promotion { terms: 1, words: 1 });
+
+        let contents = fs::read_to_string(&config_path).unwrap();
+        assert!(contents.starts_with("# Project config\n"));
+        let config: crate::Config = toml::from_str(&contents).unwrap();
+        assert_eq!(config.terms["data set"], "dataset");
+        assert_eq!(config.terms["whitelist"], "allowlist");
+        assert_eq!(config.words, vec!["Kubernetes"]);
+
+        // Promoting again adds nothing new
+        assert_eq!(
+            promote(&config_path, &groups).unwrap(),
+            Promotion::default()
+        );
+    }
+}
@@ -0,0 +1,550 @@
+use super::sources::canonical_entries;
+use anyhow::{Context, Result};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+/// Words expanded from a Hunspell `.dic`/`.aff` pair
+#[derive(Debug, Default)]
+pub struct HunspellWords {
+    /// Every accepted form, lowercased and sorted
+    pub words: Vec<String>,
+    /// Accepted forms that must never be offered as suggestions
+    pub nosuggest: Vec<String>,
+    /// Forms written with capitals, e.g. "GitHub"
+    pub canonical: Vec<String>,
+}
+
+type Flag = String;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FlagType {
+    Short,
+    Long,
+    Numeric,
+    Utf8,
+}
+
+#[derive(Debug, Clone)]
+enum Condition {
+    Any,
+    Char(char),
+    Set(Vec<char>, bool),
+}
+
+impl Condition {
+    fn matches(&self, ch: char) -> bool {
+        match self {
+            Condition::Any => true,
+            Condition::Char(c) => *c == ch,
+            Condition::Set(chars, negated) => chars.contains(&ch) != *negated,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct AffixRule {
+    strip: String,
+    add: String,
+    condition: Vec<Condition>,
+    continuation: Vec<Flag>,
+}
+
+impl AffixRule {
+    fn apply_suffix(&self, word: &str) -> Option<String> {
+        let chars: Vec<char> = word.chars().collect();
+        if !word.ends_with(&self.strip) || chars.len() < self.condition.len() {
+            return None;
+        }
+
+        let tail = &chars[chars.len() - self.condition.len()..];
+        if !self
+            .condition
+            .iter()
+            .zip(tail)
+            .all(|(c, &ch)| c.matches(ch))
+        {
+            return None;
+        }
+
+        let stem = &word[..word.len() - self.strip.len()];
+        Some(format!("{}{}", stem, self.add))
+    }
+
+    fn apply_prefix(&self, word: &str) -> Option<String> {
+        let chars: Vec<char> = word.chars().collect();
+        if !word.starts_with(&self.strip) || chars.len() < self.condition.len() {
+            return None;
+        }
+
+        let head = &chars[..self.condition.len()];
+        if !self
+            .condition
+            .iter()
+            .zip(head)
+            .all(|(c, &ch)| c.matches(ch))
+        {
+            return None;
+        }
+
+        Some(format!("{}{}", self.add, &word[self.strip.len()..]))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Affix {
+    cross_product: bool,
+    rules: Vec<AffixRule>,
+}
+
+#[derive(Debug)]
+struct AffixFile {
+    flag_type: FlagType,
+    prefixes: HashMap<Flag, Affix>,
+    suffixes: HashMap<Flag, Affix>,
+    aliases: Vec<Vec<Flag>>,
+    nosuggest: Option<Flag>,
+    forbidden: Option<Flag>,
+    needaffix: Option<Flag>,
+    only_in_compound: Option<Flag>,
+}
+
+impl AffixFile {
+    fn parse(content: &str) -> Result<Self> {
+        let mut aff = Self {
+            flag_type: FlagType::Short,
+            prefixes: HashMap::new(),
+            suffixes: HashMap::new(),
+            aliases: Vec::new(),
+            nosuggest: None,
+            forbidden: None,
+            needaffix: None,
+            only_in_compound: None,
+        };
+        let mut alias_header_seen = false;
+
+        for (line_num, line) in content.lines().enumerate() {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            let Some(&keyword) = tokens.first() else {
+                continue;
+            };
+
+            match (keyword, tokens.get(1)) {
+                ("FLAG", Some(&flag_type)) => {
+                    aff.flag_type = match flag_type {
+                        "long" => FlagType::Long,
+                        "num" => FlagType::Numeric,
+                        "UTF-8" => FlagType::Utf8,
+                        other => anyhow::bail!("Unsupported FLAG type: {}", other),
+                    };
+                }
+                ("AF", Some(&flags)) => {
+                    // The first AF line only declares the number of aliases
+                    if alias_header_seen {
+                        aff.aliases.push(parse_flags(flags, aff.flag_type, &[]));
+                    } else {
+                        alias_header_seen = true;
+                    }
+                }
+                ("NOSUGGEST", Some(&flag)) => aff.nosuggest = Some(flag.to_string()),
+                ("FORBIDDENWORD", Some(&flag)) => aff.forbidden = Some(flag.to_string()),
+                ("NEEDAFFIX" | "PSEUDOROOT", Some(&flag)) => aff.needaffix = Some(flag.to_string()),
+                ("ONLYINCOMPOUND", Some(&flag)) => aff.only_in_compound = Some(flag.to_string()),
+                ("PFX" | "SFX", Some(&flag)) => {
+                    aff.parse_affix_line(keyword == "PFX", flag, &tokens)
+                        .with_context(|| format!("Invalid affix on line {}", line_num + 1))?;
+                }
+                _ => {}
+            }
+        }
+
+        Ok(aff)
+    }
+
+    fn parse_affix_line(&mut self, prefix: bool, flag: &str, tokens: &[&str]) -> Result<()> {
+        let flag_type = self.flag_type;
+        let aliases = self.aliases.clone();
+        let table = if prefix {
+            &mut self.prefixes
+        } else {
+            &mut self.suffixes
+        };
+
+        // Header: "SFX D Y 4"
+        let Some(affix) = table.get_mut(flag) else {
+            table.insert(
+                flag.to_string(),
+                Affix {
+                    cross_product: tokens.get(2) == Some(&"Y"),
+                    rules: Vec::new(),
+                },
+            );
+            return Ok(());
+        };
+
+        // Rule: "SFX D y ied [^aeiou]y"
+        let strip = tokens.get(2).context("Missing strip characters")?;
+        let add = tokens.get(3).context("Missing affix")?;
+        let (add, continuation) = match add.split_once('/') {
+            Some((add, flags)) => (add, parse_flags(flags, flag_type, &aliases)),
+            None => (*add, Vec::new()),
+        };
+
+        affix.rules.push(AffixRule {
+            strip: zero_to_empty(strip),
+            add: zero_to_empty(add),
+            condition: parse_condition(tokens.get(4).unwrap_or(&".")),
+            continuation,
+        });
+
+        Ok(())
+    }
+
+    fn parse_flags(&self, flags: &str) -> Vec<Flag> {
+        parse_flags(flags, self.flag_type, &self.aliases)
+    }
+
+    fn has_flag(flags: &[Flag], flag: &Option<Flag>) -> bool {
+        flag.as_ref().is_some_and(|f| flags.contains(f))
+    }
+
+    /// Generate all forms of a stem allowed by its affix flags
+    fn forms(&self, stem: &str, flags: &[Flag]) -> Vec<String> {
+        if Self::has_flag(flags, &self.only_in_compound) {
+            return Vec::new();
+        }
+
+        let mut forms = Vec::new();
+        if !Self::has_flag(flags, &self.needaffix) {
+            forms.push(stem.to_string());
+        }
+
+        let mut cross_suffixed = Vec::new();
+        for affix in flags.iter().filter_map(|f| self.suffixes.get(f)) {
+            for rule in &affix.rules {
+                let Some(form) = rule.apply_suffix(stem) else {
+                    continue;
+                };
+
+                // One level of continuation classes ("SFX A 0 s/B .")
+                for cont in rule
+                    .continuation
+                    .iter()
+                    .filter_map(|f| self.suffixes.get(f))
+                {
+                    forms.extend(cont.rules.iter().filter_map(|r| r.apply_suffix(&form)));
+                }
+
+                if affix.cross_product {
+                    cross_suffixed.push(form.clone());
+                }
+                if !Self::has_flag(&rule.continuation, &self.needaffix) {
+                    forms.push(form);
+                }
+            }
+        }
+
+        for affix in flags.iter().filter_map(|f| self.prefixes.get(f)) {
+            for rule in &affix.rules {
+                forms.extend(rule.apply_prefix(stem));
+                if affix.cross_product {
+                    forms.extend(cross_suffixed.iter().filter_map(|s| rule.apply_prefix(s)));
+                }
+            }
+        }
+
+        forms
+    }
+}
+
+/// Load a Hunspell dictionary, expanding affixes; the `.aff` file must sit next to `dic_path`
+pub fn load(dic_path: &Path) -> Result<HunspellWords> {
+    let aff_path = dic_path.with_extension("aff");
+    let aff_bytes = fs::read(&aff_path)
+        .with_context(|| format!("Failed to read affix file: {}", aff_path.display()))?;
+    let dic_bytes = fs::read(dic_path)
+        .with_context(|| format!("Failed to read dictionary: {}", dic_path.display()))?;
+
+    parse(&dic_bytes, &aff_bytes)
+        .with_context(|| format!("Failed to load Hunspell dictionary: {}", dic_path.display()))
+}
+
+/// Expand raw `.dic` and `.aff` contents, decoding them with the `SET` encoding
+pub fn parse(dic_bytes: &[u8], aff_bytes: &[u8]) -> Result<HunspellWords> {
+    let encoding = detect_encoding(aff_bytes);
+    let aff = AffixFile::parse(&decode(aff_bytes, &encoding)?)?;
+    let dic = decode(dic_bytes, &encoding)?;
+
+    Ok(expand(&aff, &dic))
+}
+
+fn expand(aff: &AffixFile, dic: &str) -> HunspellWords {
+    let mut words = HashSet::new();
+    let mut nosuggest = HashSet::new();
+    let mut forbidden = HashSet::new();
+    let mut written = HashSet::new();
+
+    // The first line holds the approximate entry count
+    for line in dic.lines().skip(1) {
+        let Some((stem, flags)) = parse_dic_line(line, aff) else {
+            continue;
+        };
+
+        let forms = aff.forms(&stem, &flags);
+        if AffixFile::has_flag(&flags, &aff.forbidden) {
+            forbidden.extend(forms.into_iter().map(|f| f.to_lowercase()));
+            continue;
+        }
+        if AffixFile::has_flag(&flags, &aff.nosuggest) {
+            nosuggest.extend(forms.iter().map(|f| f.to_lowercase()));
+        }
+        words.extend(forms.iter().map(|f| f.to_lowercase()));
+        written.extend(forms);
+    }
+
+    // Forbidden words win even when another stem generates them
+    let mut words: Vec<String> = words.difference(&forbidden).cloned().collect();
+    let mut nosuggest: Vec<String> = nosuggest.difference(&forbidden).cloned().collect();
+    words.sort();
+    nosuggest.sort();
+    let canonical = canonical_entries(written.iter().map(String::as_str))
+        .into_iter()
+        .filter(|form| !forbidden.contains(&form.to_lowercase()))
+        .collect();
+
+    HunspellWords {
+        words,
+        nosuggest,
+        canonical,
+    }
+}
+
+/// Split "word/FLAGS\tmorphology" into the word and its flags
+fn parse_dic_line(line: &str, aff: &AffixFile) -> Option<(String, Vec<Flag>)> {
+    let entry = line.split_whitespace().next()?;
+
+    // A slash escaped with a backslash is part of the word
+    let mut split_at = None;
+    let mut prev = ' ';
+    for (i, ch) in entry.char_indices() {
+        if ch == '/' && prev != '\\' && i > 0 {
+            split_at = Some(i);
+            break;
+        }
+        prev = ch;
+    }
+
+    let (word, flags) = match split_at {
+        Some(i) => (&entry[..i], aff.parse_flags(&entry[i + 1..])),
+        None => (entry, Vec::new()),
+    };
+
+    Some((word.replace("\\/", "/"), flags))
+}
+
+fn parse_flags(flags: &str, flag_type: FlagType, aliases: &[Vec<Flag>]) -> Vec<Flag> {
+    // With AF aliases, flag fields are 1-based indexes into the alias table
+    if !aliases.is_empty() {
+        if let Ok(index) = flags.parse::<usize>() {
+            return aliases
+                .get(index.wrapping_sub(1))
+                .cloned()
+                .unwrap_or_default();
+        }
+    }
+
+    match flag_type {
+        FlagType::Short | FlagType::Utf8 => flags.chars().map(String::from).collect(),
+        FlagType::Long => flags
+            .chars()
+            .collect::<Vec<_>>()
+            .chunks(2)
+            .map(|c| c.iter().collect())
+            .collect(),
+        FlagType::Numeric => flags.split(',').map(|f| f.trim().to_string()).collect(),
+    }
+}
+
+/// Parse a condition such as "[^aeiou]y" into per-character matchers
+fn parse_condition(condition: &str) -> Vec<Condition> {
+    if condition == "." {
+        return Vec::new();
+    }
+
+    let mut result = Vec::new();
+    let mut chars = condition.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '.' => result.push(Condition::Any),
+            '[' => {
+                let mut set = Vec::new();
+                let mut negated = false;
+                for c in chars.by_ref() {
+                    match c {
+                        ']' => break,
+                        '^' if set.is_empty() && !negated => negated = true,
+                        c => set.push(c),
+                    }
+                }
+                result.push(Condition::Set(set, negated));
+            }
+            c => result.push(Condition::Char(c)),
+        }
+    }
+    result
+}
+
+fn zero_to_empty(value: &str) -> String {
+    if value == "0" {
+        String::new()
+    } else {
+        value.to_string()
+    }
+}
+
+fn detect_encoding(aff: &[u8]) -> String {
+    String::from_utf8_lossy(aff)
+        .lines()
+        .find_map(|line| line.strip_prefix("SET "))
+        .map(|enc| enc.trim().to_uppercase())
+        .unwrap_or_else(|| "ISO8859-1".to_string())
+}
+
+fn decode(bytes: &[u8], encoding: &str) -> Result<String> {
+    match encoding {
+        "UTF-8" | "UTF8" => {
+            String::from_utf8(bytes.to_vec()).context("Invalid UTF-8 in dictionary")
+        }
+        // Latin-1 maps every byte directly to the code point of the same value
+        "ISO8859-1" | "ISO-8859-1" => Ok(bytes.iter().map(|&b| b as char).collect()),
+        other => {
+            // Hunspell names that aren't WHATWG labels, e.g. "microsoft-cp1251"
+            let label = match other.strip_prefix("MICROSOFT-").unwrap_or(other) {
+                "TIS620-2533" => "TIS-620",
+                label => label,
+            };
+            let decoder = encoding_rs::Encoding::for_label(label.as_bytes())
+                .with_context(|| format!("Unsupported dictionary encoding: {}", other))?;
+            let (text, had_errors) = decoder.decode_without_bom_handling(bytes);
+            if had_errors {
+                anyhow::bail!("Invalid {} in dictionary", other);
+            }
+            Ok(text.into_owned())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    const AFF: &str = "\
+SET UTF-8
+NOSUGGEST !
+FORBIDDENWORD *
+NEEDAFFIX %
+
+PFX A Y 1
+PFX A   0     re         .
+
+SFX D Y 4
+SFX D   0     d          e
+SFX D   y     ied        [^aeiou]y
+SFX D   0     ed         [^ey]
+SFX D   0     ed         [aeiou]y
+
+SFX S Y 1
+SFX S   0     s          .
+
+SFX L N 1
+SFX L   0     ly/S       .
+";
+
+    const DIC: &str = "\
+6
+create/ADS
+carry/D
+damn/!S
+work/S
+works/*
+kind/%L
+";
+
+    fn load_test_dictionary() -> HunspellWords {
+        let dir = tempdir().unwrap();
+        let dic_path = dir.path().join("test.dic");
+        fs::write(dir.path().join("test.aff"), AFF).unwrap();
+        fs::write(&dic_path, DIC).unwrap();
+        load(&dic_path).unwrap()
+    }
+
+    #[test]
+    fn test_affix_expansion() {
+        let dict = load_test_dictionary();
+        for word in [
+            "create",
+            "created",
+            "creates",
+            "recreate",
+            "recreated",
+            "recreates",
+            "carry",
+            "carried",
+            "kindly",
+            "kindlys",
+        ] {
+            assert!(dict.words.contains(&word.to_string()), "missing {}", word);
+        }
+        assert!(!dict.words.contains(&"carryed".to_string()));
+    }
+
+    #[test]
+    fn test_flags() {
+        let dict = load_test_dictionary();
+
+        // FORBIDDENWORD overrides forms generated from other stems
+        assert!(dict.words.contains(&"work".to_string()));
+        assert!(!dict.words.contains(&"works".to_string()));
+
+        // NOSUGGEST words are accepted but listed separately
+        assert!(dict.words.contains(&"damns".to_string()));
+        assert_eq!(dict.nosuggest, vec!["damn", "damns"]);
+
+        // NEEDAFFIX stems are only valid with an affix
+        assert!(!dict.words.contains(&"kind".to_string()));
+    }
+
+    #[test]
+    fn test_long_flags_and_aliases() {
+        let aff = AffixFile::parse("FLAG long\nAF 1\nAF AaBb\nSFX Aa Y 1\nSFX Aa 0 s .\n").unwrap();
+        let (word, flags) = parse_dic_line("cat/1", &aff).unwrap();
+
+        assert_eq!(word, "cat");
+        assert_eq!(flags, vec!["Aa", "Bb"]);
+        assert_eq!(aff.forms(&word, &flags), vec!["cat", "cats"]);
+    }
+
+    #[test]
+    fn test_canonical_forms() {
+        let aff = b"SFX S Y 1\nSFX S 0 s .\n";
+        let dict = parse(b"3\nGitHub/S\nMark\nmark\n", aff).unwrap();
+
+        assert_eq!(dict.words, vec!["github", "githubs", "mark"]);
+        assert_eq!(dict.canonical, vec!["GitHub", "GitHubs"]);
+    }
+
+    #[test]
+    fn test_legacy_encodings() {
+        let koi8 = parse(b"1\n\xcb\xcf\xd4\n", b"SET KOI8-R\n").unwrap();
+        assert_eq!(koi8.words, vec!["кот"]);
+
+        let cp1251 = parse(b"1\n\xea\xee\xf2\n", b"SET microsoft-cp1251\n").unwrap();
+        assert_eq!(cp1251.words, vec!["кот"]);
+
+        let latin2 = parse(b"1\n\xb3\xb1ka\n", b"SET ISO8859-2\n").unwrap();
+        assert_eq!(latin2.words, vec!["łąka"]);
+
+        assert!(parse(b"1\nword\n", b"SET ISCII-DEVANAGARI\n").is_err());
+    }
+}
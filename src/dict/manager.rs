@@ -1,14 +1,12 @@
+use super::manifest::{self, DictionaryManifest, Verification, FORMAT_VERSION};
+use super::sources::{DictionarySource, FetchedWords};
+use crate::checker::dictionary::Dictionary;
 use anyhow::{Context, Result};
 use colored::*;
 use indicatif::{ProgressBar, ProgressStyle};
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
-
-// Use a specific commit hash for reproducibility and stability
-// This prevents unexpected changes from the 'master' branch
-const WORDLIST_BASE_URL: &str =
-    "https://raw.githubusercontent.com/dwyl/english-words/6e4bc58ad764c3e6df8b5be4048671962c9d6a23";
-const WORDLIST_VERSION: &str = "2023.12";
+use std::path::{Path, PathBuf};
 
 pub struct DictionaryInfo {
     pub language: String,
@@ -49,11 +47,19 @@ pub fn list_dictionaries() -> Result<()> {
             let metadata = fs::metadata(&path)?;
             let size_kb = metadata.len() / 1024;
 
+            let details = match DictionaryManifest::load(&path) {
+                Ok(Some(manifest)) => format!(
+                    "{}KB, {} words, built {}",
+                    size_kb, manifest.word_count, manifest.built_at
+                ),
+                _ => format!("{}KB, no manifest", size_kb),
+            };
+
             println!(
                 "  {} {} ({})",
                 "✓".green(),
                 language.cyan().bold(),
-                format!("{}KB", size_kb).dimmed()
+                details.dimmed()
             );
         }
     }
@@ -62,6 +68,12 @@ pub fn list_dictionaries() -> Result<()> {
         println!("{}", "No dictionaries found.".yellow());
     }
 
+    let packs = crate::checker::packs::available();
+    if !packs.is_empty() {
+        println!();
+        println!("{} {}", "Bundled word packs:".bold(), packs.join(", "));
+    }
+
     println!();
     println!(
         "Data directory: {}",
@@ -71,33 +83,43 @@ pub fn list_dictionaries() -> Result<()> {
     Ok(())
 }
 
-pub fn download_dictionary(language: &str) -> Result<()> {
+pub fn download_dictionary(
+    language: &str,
+    sources: &HashMap<String, DictionarySource>,
+) -> Result<()> {
+    let source = sources.get(language).with_context(|| {
+        format!(
+            "Language '{}' has no dictionary source. Add a [sources.{}] entry to your config or use `spellchk dict import`.",
+            language, language
+        )
+    })?;
+
     println!(
-        "{} dictionary for {} (version: {})...",
+        "{} dictionary for {}...",
         "Downloading".cyan().bold(),
-        language.yellow(),
-        WORDLIST_VERSION.dimmed()
+        language.yellow()
+    );
+
+    install_from_source(language, source)
+}
+
+/// Install a dictionary from a local file, `file://` URL or http(s) URL
+pub fn import_dictionary(language: &str, source: &DictionarySource) -> Result<()> {
+    println!(
+        "{} dictionary for {}...",
+        "Importing".cyan().bold(),
+        language.yellow()
     );
 
+    install_from_source(language, source)
+}
+
+fn install_from_source(language: &str, source: &DictionarySource) -> Result<()> {
     let data_dir = crate::config::Config::data_dir().context("Failed to get data directory")?;
 
     fs::create_dir_all(&data_dir).context("Failed to create data directory")?;
 
-    // Download from pinned commit hash for reproducibility
-    // Using specific commit instead of 'master' prevents unexpected changes
-    let wordlist_url = match language {
-        "en_US" | "en_GB" => {
-            format!("{}/words_alpha.txt", WORDLIST_BASE_URL)
-        }
-        other => {
-            anyhow::bail!(
-                "Language '{}' is not supported. Only 'en_US' and 'en_GB' are currently available.",
-                other
-            );
-        }
-    };
-
-    println!("Source: {}", wordlist_url.dimmed());
+    println!("Source: {}", source.url.dimmed());
 
     // Download wordlist
     let pb = ProgressBar::new_spinner();
@@ -108,29 +130,33 @@ pub fn download_dictionary(language: &str) -> Result<()> {
     );
     pb.set_message("Downloading...");
 
-    let response =
-        reqwest::blocking::get(&wordlist_url).context("Failed to download dictionary")?;
+    let fetched = source.fetch_words();
+    pb.finish_and_clear();
+    let fetched = fetched?;
 
-    if !response.status().is_success() {
-        anyhow::bail!("Failed to download dictionary: HTTP {}", response.status());
+    let unverified = source.unverified();
+    if unverified.is_empty() {
+        println!("{} Checksum verified", "✓".green());
+    }
+    for location in unverified {
+        println!(
+            "{} No checksum for {}, not verified",
+            "!".yellow().bold(),
+            location.dimmed()
+        );
+    }
+    println!("Found {} words", fetched.words.len().to_string().yellow());
+    if !fetched.frequencies.is_empty() {
+        println!(
+            "Found {} word frequencies",
+            fetched.frequencies.len().to_string().yellow()
+        );
     }
-
-    let content = response.text()?;
-    pb.finish_with_message("Download complete");
-
-    // Parse words
-    println!("{}", "Building dictionary...".cyan());
-    let words: Vec<String> = content
-        .lines()
-        .map(|line| line.trim().to_lowercase())
-        .filter(|line| !line.is_empty() && line.len() > 1)
-        .collect();
-
-    println!("Found {} words", words.len().to_string().yellow());
 
     // Build FST dictionary
+    println!("{}", "Building dictionary...".cyan());
     let dict_path = data_dir.join(format!("{}.dict", language));
-    crate::checker::dictionary::Dictionary::build_from_words(&words, &dict_path)?;
+    write_dictionary(language, &dict_path, &fetched, source)?;
 
     println!(
         "{} Dictionary installed: {}",
@@ -141,7 +167,73 @@ pub fn download_dictionary(language: &str) -> Result<()> {
     Ok(())
 }
 
-pub fn update_dictionaries() -> Result<()> {
+/// Write the FST set, the NOSUGGEST sidecar if needed, and the manifest
+fn write_dictionary(
+    language: &str,
+    dict_path: &Path,
+    fetched: &FetchedWords,
+    source: &DictionarySource,
+) -> Result<DictionaryManifest> {
+    let word_count =
+        Dictionary::build_with_frequencies(&fetched.words, &fetched.frequencies, dict_path)?;
+
+    let nosuggest_path = Dictionary::nosuggest_path(dict_path);
+    let nosuggest_sha256 = if !fetched.nosuggest.is_empty() {
+        Dictionary::build_from_words(&fetched.nosuggest, &nosuggest_path)?;
+        Some(manifest::file_sha256(&nosuggest_path)?)
+    } else {
+        if nosuggest_path.exists() {
+            fs::remove_file(&nosuggest_path).context("Failed to remove stale NOSUGGEST list")?;
+        }
+        None
+    };
+
+    let canonical_path = Dictionary::canonical_path(dict_path);
+    let canonical_sha256 = if !fetched.canonical.is_empty() {
+        Dictionary::build_from_words(&fetched.canonical, &canonical_path)?;
+        Some(manifest::file_sha256(&canonical_path)?)
+    } else {
+        if canonical_path.exists() {
+            fs::remove_file(&canonical_path).context("Failed to remove stale canonical forms")?;
+        }
+        None
+    };
+
+    // Languages without a phonetic encoder get no index
+    let phonetic_path = Dictionary::phonetic_path(dict_path);
+    let phonetic_sha256 = match crate::checker::phonetic::encoder_for(language) {
+        Some(encoder) => {
+            Dictionary::build_phonetic_index(&fetched.words, encoder.as_ref(), &phonetic_path)?;
+            Some(manifest::file_sha256(&phonetic_path)?)
+        }
+        None => {
+            if phonetic_path.exists() {
+                fs::remove_file(&phonetic_path).context("Failed to remove stale phonetic index")?;
+            }
+            None
+        }
+    };
+
+    let manifest = DictionaryManifest {
+        format_version: FORMAT_VERSION,
+        language: language.to_string(),
+        source: source.url.clone(),
+        source_sha256: fetched.sha256.clone(),
+        dict_sha256: manifest::file_sha256(dict_path)?,
+        nosuggest_sha256,
+        phonetic_sha256,
+        canonical_sha256,
+        word_count,
+        built_at: manifest::utc_timestamp(),
+        license: source.license.clone(),
+        frequency_source: source.frequency_url.clone(),
+    };
+    manifest.save(dict_path)?;
+
+    Ok(manifest)
+}
+
+pub fn update_dictionaries(sources: &HashMap<String, DictionarySource>) -> Result<()> {
     let data_dir = crate::config::Config::data_dir().context("Failed to get data directory")?;
 
     if !data_dir.exists() {
@@ -181,7 +273,18 @@ pub fn update_dictionaries() -> Result<()> {
     println!();
 
     for language in languages {
-        download_dictionary(&language)?;
+        // Imported dictionaries have no source to refresh from
+        if !sources.contains_key(&language) {
+            println!(
+                "{} {} (no configured source)",
+                "Skipping".yellow(),
+                language.cyan()
+            );
+            println!();
+            continue;
+        }
+
+        download_dictionary(&language, sources)?;
         println!();
     }
 
@@ -213,13 +316,34 @@ pub fn show_info(language: &str) -> Result<()> {
     println!("{}", format!("Dictionary: {}", language).bold());
     println!("  Path: {}", dict_path.display());
     println!("  Size: {} KB", metadata.len() / 1024);
-    println!("  Version: {}", WORDLIST_VERSION);
     println!("  Format: FST (Finite State Transducer)");
 
+    match DictionaryManifest::load(&dict_path)? {
+        Some(manifest) => {
+            println!("  Format version: {}", manifest.format_version);
+            println!("  Source: {}", manifest.source);
+            println!("  Source SHA-256: {}", manifest.source_sha256);
+            println!("  Built: {}", manifest.built_at);
+            println!(
+                "  License: {}",
+                manifest.license.as_deref().unwrap_or("Unknown")
+            );
+            if let Some(frequency_source) = &manifest.frequency_source {
+                println!("  Frequencies: {}", frequency_source);
+            }
+        }
+        None => {
+            println!(
+                "  Manifest: {} (reinstall to create one)",
+                "missing".yellow()
+            );
+        }
+    }
+
     // Try to load and get word count
-    match crate::checker::dictionary::Dictionary::load(language) {
-        Ok(_dict) => {
-            println!("  Words: {}", "Unknown".yellow());
+    match Dictionary::load_from_path(&dict_path) {
+        Ok(dict) => {
+            println!("  Words: {}", dict.len());
         }
         Err(e) => {
             println!("  {}: {}", "Error loading dictionary".red(), e);
@@ -229,6 +353,88 @@ pub fn show_info(language: &str) -> Result<()> {
     Ok(())
 }
 
+/// Check installed dictionaries (or one language) against their manifests
+pub fn verify_dictionaries(language: Option<&str>) -> Result<()> {
+    let data_dir = crate::config::Config::data_dir().context("Failed to get data directory")?;
+
+    let mut dict_paths = Vec::new();
+    if let Some(language) = language {
+        dict_paths.push(data_dir.join(format!("{}.dict", language)));
+    } else if data_dir.exists() {
+        for entry in fs::read_dir(&data_dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|s| s.to_str()) == Some("dict") {
+                dict_paths.push(path);
+            }
+        }
+        dict_paths.sort();
+    }
+
+    if dict_paths.is_empty() {
+        println!("{}", "No dictionaries installed.".yellow());
+        return Ok(());
+    }
+
+    let mut failures = 0;
+    for dict_path in &dict_paths {
+        let language = dict_path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("unknown");
+
+        if !dict_path.exists() {
+            println!("  {} {} not installed", "✗".red().bold(), language.cyan());
+            failures += 1;
+            continue;
+        }
+
+        match DictionaryManifest::verify(dict_path)? {
+            Verification::Ok => {
+                println!("  {} {}", "✓".green(), language.cyan().bold());
+            }
+            Verification::MissingManifest => {
+                println!(
+                    "  {} {} has no manifest (reinstall to create one)",
+                    "!".yellow().bold(),
+                    language.cyan()
+                );
+            }
+            Verification::UnsupportedFormat(version) => {
+                println!(
+                    "  {} {} uses unsupported format version {}",
+                    "✗".red().bold(),
+                    language.cyan(),
+                    version
+                );
+                failures += 1;
+            }
+            Verification::ChecksumMismatch(path) => {
+                println!(
+                    "  {} {} checksum mismatch: {}",
+                    "✗".red().bold(),
+                    language.cyan(),
+                    path.display()
+                );
+                failures += 1;
+            }
+        }
+    }
+
+    if failures > 0 {
+        anyhow::bail!(
+            "{} {} failed verification",
+            failures,
+            if failures == 1 {
+                "dictionary"
+            } else {
+                "dictionaries"
+            }
+        );
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
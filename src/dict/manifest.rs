@@ -0,0 +1,196 @@
+use super::sources::sha256_hex;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Version of the on-disk dictionary layout written by this build
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Metadata written next to every installed `.dict` file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DictionaryManifest {
+    pub format_version: u32,
+    pub language: String,
+    pub source: String,
+    pub source_sha256: String,
+    pub dict_sha256: String,
+    #[serde(default)]
+    pub nosuggest_sha256: Option<String>,
+    #[serde(default)]
+    pub phonetic_sha256: Option<String>,
+    #[serde(default)]
+    pub canonical_sha256: Option<String>,
+    pub word_count: usize,
+    pub built_at: String,
+    #[serde(default)]
+    pub license: Option<String>,
+    /// Frequency list the word frequencies came from, if any
+    #[serde(default)]
+    pub frequency_source: Option<String>,
+}
+
+/// Outcome of checking an installed dictionary against its manifest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verification {
+    Ok,
+    MissingManifest,
+    UnsupportedFormat(u32),
+    ChecksumMismatch(PathBuf),
+}
+
+impl DictionaryManifest {
+    /// Path of the manifest for a dictionary file
+    pub fn path_for(dict_path: &Path) -> PathBuf {
+        dict_path.with_extension("manifest.json")
+    }
+
+    pub fn load(dict_path: &Path) -> Result<Option<Self>> {
+        let path = Self::path_for(dict_path);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read manifest: {}", path.display()))?;
+        let manifest = serde_json::from_str(&contents)
+            .with_context(|| format!("Failed to parse manifest: {}", path.display()))?;
+        Ok(Some(manifest))
+    }
+
+    pub fn save(&self, dict_path: &Path) -> Result<()> {
+        let path = Self::path_for(dict_path);
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(&path, json + "\n")
+            .with_context(|| format!("Failed to write manifest: {}", path.display()))
+    }
+
+    /// Check the dictionary files against the checksums recorded at build time
+    pub fn verify(dict_path: &Path) -> Result<Verification> {
+        let Some(manifest) = Self::load(dict_path)? else {
+            return Ok(Verification::MissingManifest);
+        };
+
+        if manifest.format_version > FORMAT_VERSION {
+            return Ok(Verification::UnsupportedFormat(manifest.format_version));
+        }
+
+        if file_sha256(dict_path)? != manifest.dict_sha256 {
+            return Ok(Verification::ChecksumMismatch(dict_path.to_path_buf()));
+        }
+
+        let nosuggest_path = crate::checker::dictionary::Dictionary::nosuggest_path(dict_path);
+        if let Some(expected) = &manifest.nosuggest_sha256 {
+            if !nosuggest_path.exists() || file_sha256(&nosuggest_path)? != *expected {
+                return Ok(Verification::ChecksumMismatch(nosuggest_path));
+            }
+        }
+
+        let phonetic_path = crate::checker::dictionary::Dictionary::phonetic_path(dict_path);
+        if let Some(expected) = &manifest.phonetic_sha256 {
+            if !phonetic_path.exists() || file_sha256(&phonetic_path)? != *expected {
+                return Ok(Verification::ChecksumMismatch(phonetic_path));
+            }
+        }
+
+        let canonical_path = crate::checker::dictionary::Dictionary::canonical_path(dict_path);
+        if let Some(expected) = &manifest.canonical_sha256 {
+            if !canonical_path.exists() || file_sha256(&canonical_path)? != *expected {
+                return Ok(Verification::ChecksumMismatch(canonical_path));
+            }
+        }
+
+        Ok(Verification::Ok)
+    }
+}
+
+pub fn file_sha256(path: &Path) -> Result<String> {
+    let bytes = fs::read(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    Ok(sha256_hex(&bytes))
+}
+
+/// Current UTC time as an RFC 3339 timestamp
+pub fn utc_timestamp() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    format_timestamp(secs)
+}
+
+fn format_timestamp(secs: u64) -> String {
+    let days = (secs / 86_400) as i64;
+    let rem = secs % 86_400;
+
+    // Civil date from days since the epoch (Howard Hinnant's algorithm)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        rem / 3_600,
+        rem % 3_600 / 60,
+        rem % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_timestamp(1_709_210_096), "2024-02-29T12:34:56Z");
+    }
+
+    #[test]
+    fn test_verify() {
+        let dir = tempdir().unwrap();
+        let dict_path = dir.path().join("xx.dict");
+        fs::write(&dict_path, b"fst bytes").unwrap();
+
+        assert_eq!(
+            DictionaryManifest::verify(&dict_path).unwrap(),
+            Verification::MissingManifest
+        );
+
+        let manifest = DictionaryManifest {
+            format_version: FORMAT_VERSION,
+            language: "xx".to_string(),
+            source: "words.txt".to_string(),
+            source_sha256: String::new(),
+            dict_sha256: file_sha256(&dict_path).unwrap(),
+            nosuggest_sha256: None,
+            phonetic_sha256: None,
+            canonical_sha256: None,
+            word_count: 2,
+            built_at: utc_timestamp(),
+            license: None,
+            frequency_source: None,
+        };
+        manifest.save(&dict_path).unwrap();
+        assert_eq!(
+            DictionaryManifest::verify(&dict_path).unwrap(),
+            Verification::Ok
+        );
+
+        fs::write(&dict_path, b"tampered").unwrap();
+        assert_eq!(
+            DictionaryManifest::verify(&dict_path).unwrap(),
+            Verification::ChecksumMismatch(dict_path.clone())
+        );
+    }
+}
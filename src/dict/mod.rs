@@ -1,3 +1,6 @@
+pub mod hunspell;
 pub mod manager;
+pub mod manifest;
+pub mod sources;
 
 pub use manager::{download_dictionary, list_dictionaries, update_dictionaries};
@@ -0,0 +1,426 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+// Use a specific commit hash for reproducibility and stability
+// This prevents unexpected changes from the 'master' branch
+const WORDLIST_BASE_URL: &str =
+    "https://raw.githubusercontent.com/dwyl/english-words/6e4bc58ad764c3e6df8b5be4048671962c9d6a23";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DictFormat {
+    /// One word per line
+    #[default]
+    Wordlist,
+    /// Hunspell `.dic` with a sibling `.aff` file
+    Hunspell,
+}
+
+impl DictFormat {
+    /// Guess the format from a file name or URL
+    pub fn infer(location: &str) -> Self {
+        if location.ends_with(".dic") {
+            DictFormat::Hunspell
+        } else {
+            DictFormat::Wordlist
+        }
+    }
+}
+
+impl std::str::FromStr for DictFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "wordlist" => Ok(DictFormat::Wordlist),
+            "hunspell" => Ok(DictFormat::Hunspell),
+            _ => Err(format!("Unknown dictionary format: {}", s)),
+        }
+    }
+}
+
+/// Where to get a dictionary from: an http(s) URL, a `file://` URL or a local path
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DictionarySource {
+    pub url: String,
+
+    #[serde(default)]
+    pub format: DictFormat,
+
+    /// Expected SHA-256 of the downloaded file (hex)
+    #[serde(default)]
+    pub sha256: Option<String>,
+
+    /// Location of the `.aff` file for Hunspell sources (default: `url` with `.aff`)
+    #[serde(default)]
+    pub aff_url: Option<String>,
+
+    /// Expected SHA-256 of the `.aff` file (hex)
+    #[serde(default)]
+    pub aff_sha256: Option<String>,
+
+    /// License of the word list, recorded in the dictionary manifest
+    #[serde(default)]
+    pub license: Option<String>,
+
+    /// Optional word frequency list used to rank suggestions
+    #[serde(default)]
+    pub frequency_url: Option<String>,
+
+    /// Expected SHA-256 of the frequency list (hex)
+    #[serde(default)]
+    pub frequency_sha256: Option<String>,
+}
+
+/// Words fetched from a source, with the checksum of the downloaded file
+#[derive(Debug, Clone, Default)]
+pub struct FetchedWords {
+    pub words: Vec<String>,
+    pub nosuggest: Vec<String>,
+    /// Entries written with capitals, e.g. "GitHub"
+    pub canonical: Vec<String>,
+    /// Word frequencies from the source's frequency list, if any
+    pub frequencies: HashMap<String, u64>,
+    pub sha256: String,
+}
+
+impl DictionarySource {
+    pub fn new(url: impl Into<String>, format: DictFormat) -> Self {
+        Self {
+            url: url.into(),
+            format,
+            sha256: None,
+            aff_url: None,
+            aff_sha256: None,
+            license: None,
+            frequency_url: None,
+            frequency_sha256: None,
+        }
+    }
+
+    /// Locations `fetch_words` reads without a checksum to verify them against
+    pub fn unverified(&self) -> Vec<String> {
+        let mut unverified = Vec::new();
+        if self.sha256.is_none() {
+            unverified.push(self.url.clone());
+        }
+        if self.format == DictFormat::Hunspell && self.aff_sha256.is_none() {
+            unverified.push(self.aff_location());
+        }
+        if let (Some(location), None) = (&self.frequency_url, &self.frequency_sha256) {
+            unverified.push(location.clone());
+        }
+        unverified
+    }
+
+    fn aff_location(&self) -> String {
+        self.aff_url
+            .clone()
+            .unwrap_or_else(|| match self.url.strip_suffix(".dic") {
+                Some(stem) => format!("{}.aff", stem),
+                None => format!("{}.aff", self.url),
+            })
+    }
+
+    /// Fetch, verify and parse the source into accepted and NOSUGGEST words
+    pub fn fetch_words(&self) -> Result<FetchedWords> {
+        let content = fetch(&self.url)?;
+        let sha256 = sha256_hex(&content);
+        if let Some(expected) = &self.sha256 {
+            verify_sha256(&sha256, expected)
+                .with_context(|| format!("Integrity check failed for {}", self.url))?;
+        }
+
+        let (words, nosuggest, canonical) = match self.format {
+            DictFormat::Wordlist => {
+                let (words, canonical) = parse_wordlist(&String::from_utf8_lossy(&content));
+                (words, Vec::new(), canonical)
+            }
+            DictFormat::Hunspell => {
+                let aff_location = self.aff_location();
+                let aff = fetch(&aff_location)?;
+                if let Some(expected) = &self.aff_sha256 {
+                    verify_sha256(&sha256_hex(&aff), expected)
+                        .with_context(|| format!("Integrity check failed for {}", aff_location))?;
+                }
+                let hunspell = super::hunspell::parse(&content, &aff)?;
+                (hunspell.words, hunspell.nosuggest, hunspell.canonical)
+            }
+        };
+
+        let frequencies = match &self.frequency_url {
+            Some(location) => {
+                let list = fetch(location)?;
+                if let Some(expected) = &self.frequency_sha256 {
+                    verify_sha256(&sha256_hex(&list), expected)
+                        .with_context(|| format!("Integrity check failed for {}", location))?;
+                }
+                parse_frequencies(&String::from_utf8_lossy(&list))
+            }
+            None => HashMap::new(),
+        };
+
+        Ok(FetchedWords {
+            words,
+            nosuggest,
+            canonical,
+            frequencies,
+            sha256,
+        })
+    }
+}
+
+/// Built-in sources, overridable per language from configuration
+pub fn builtin_sources() -> HashMap<String, DictionarySource> {
+    let mut wordlist = DictionarySource::new(
+        format!("{}/words_alpha.txt", WORDLIST_BASE_URL),
+        DictFormat::Wordlist,
+    );
+    wordlist.license = Some("Unlicense".to_string());
+
+    HashMap::from([
+        ("en_US".to_string(), wordlist.clone()),
+        ("en_GB".to_string(), wordlist),
+    ])
+}
+
+/// Read a dictionary file from an http(s) URL, a `file://` URL or a local path
+pub fn fetch(location: &str) -> Result<Vec<u8>> {
+    if location.starts_with("http://") || location.starts_with("https://") {
+        let response = reqwest::blocking::get(location).context("Failed to download dictionary")?;
+
+        if !response.status().is_success() {
+            anyhow::bail!("Failed to download dictionary: HTTP {}", response.status());
+        }
+
+        return Ok(response.bytes()?.to_vec());
+    }
+
+    let path = Path::new(location.strip_prefix("file://").unwrap_or(location));
+    fs::read(path).with_context(|| format!("Failed to read dictionary: {}", path.display()))
+}
+
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+fn verify_sha256(actual: &str, expected: &str) -> Result<()> {
+    if !actual.eq_ignore_ascii_case(expected.trim()) {
+        anyhow::bail!("SHA-256 mismatch: expected {}, got {}", expected, actual);
+    }
+    Ok(())
+}
+
+/// Lowercased words, and the entries written with capitals
+fn parse_wordlist(content: &str) -> (Vec<String>, Vec<String>) {
+    let entries: Vec<&str> = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && line.len() > 1)
+        .collect();
+
+    let words = entries.iter().map(|entry| entry.to_lowercase()).collect();
+    (words, canonical_entries(entries))
+}
+
+/// Entries written with capitals whose lowercase form is not an entry of its own,
+/// e.g. "GitHub" but not "Mark" when "mark" is listed too
+pub fn canonical_entries<'a>(entries: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let entries: Vec<&str> = entries.into_iter().collect();
+    let lowercase: HashSet<&str> = entries
+        .iter()
+        .copied()
+        .filter(|entry| !entry.chars().any(char::is_uppercase))
+        .collect();
+
+    let mut canonical: Vec<String> = entries
+        .iter()
+        .filter(|entry| {
+            entry.chars().any(char::is_uppercase)
+                && !lowercase.contains(entry.to_lowercase().as_str())
+        })
+        .map(|entry| entry.to_string())
+        .collect();
+    canonical.sort();
+    canonical.dedup();
+    canonical
+}
+
+/// Frequency of the top word of a list without counts; word `n` gets this divided by `n`
+const RANKED_TOP_FREQUENCY: u64 = 1_000_000_000;
+
+/// Parse `word count` lines; lists without counts are ranked most frequent first and
+/// given Zipf-style counts, so ratios between words mean the same as for counted lists
+fn parse_frequencies(content: &str) -> HashMap<String, u64> {
+    let lines: Vec<&str> = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect();
+
+    let mut frequencies = HashMap::new();
+    for (rank, line) in lines.iter().enumerate() {
+        let mut fields = line.split_whitespace();
+        let Some(word) = fields.next() else {
+            continue;
+        };
+        let frequency = match fields.next() {
+            Some(count) => match count.parse() {
+                Ok(count) => count,
+                Err(_) => continue,
+            },
+            None => RANKED_TOP_FREQUENCY / (rank as u64 + 1),
+        };
+
+        // Keep the highest count when case variants collapse to one word
+        let entry = frequencies.entry(word.to_lowercase()).or_insert(0);
+        *entry = (*entry).max(frequency);
+    }
+
+    frequencies
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Serve `body` for a single HTTP request, returning the URL
+    fn serve_once(body: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0u8; 1024];
+            let _ = stream.read(&mut request);
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        });
+
+        format!("http://{}/words.txt", addr)
+    }
+
+    #[test]
+    fn test_fetch_wordlist_over_http() {
+        let body = "Hello\nworld\na\n";
+        let mut source = DictionarySource::new(serve_once(body), DictFormat::Wordlist);
+        source.sha256 = Some(sha256_hex(body.as_bytes()));
+
+        let fetched = source.fetch_words().unwrap();
+        assert_eq!(fetched.words, vec!["hello", "world"]);
+        assert!(fetched.nosuggest.is_empty());
+        assert_eq!(fetched.sha256, sha256_hex(body.as_bytes()));
+    }
+
+    #[test]
+    fn test_canonical_entries() {
+        let (words, canonical) = parse_wordlist("GitHub\nMark\nmark\nPostgreSQL\nGitHub\n");
+        assert_eq!(
+            words,
+            vec!["github", "mark", "mark", "postgresql", "github"]
+        );
+        assert_eq!(canonical, vec!["GitHub", "PostgreSQL"]);
+    }
+
+    #[test]
+    fn test_checksum_mismatch() {
+        let mut source = DictionarySource::new(serve_once("hello\n"), DictFormat::Wordlist);
+        source.sha256 = Some("00".repeat(32));
+
+        assert!(source.fetch_words().is_err());
+    }
+
+    #[test]
+    fn test_local_hunspell_source() {
+        let dir = tempfile::tempdir().unwrap();
+        let dic_path = dir.path().join("xx.dic");
+        fs::write(&dic_path, "1\ncat/S\n").unwrap();
+        fs::write(dir.path().join("xx.aff"), "SFX S Y 1\nSFX S 0 s .\n").unwrap();
+
+        let location = format!("file://{}", dic_path.display());
+        let source = DictionarySource::new(&location, DictFormat::infer(&location));
+
+        let fetched = source.fetch_words().unwrap();
+        assert_eq!(fetched.words, vec!["cat", "cats"]);
+        assert!(fetched.canonical.is_empty());
+
+        // The .aff is verified too
+        let mut source = source;
+        assert_eq!(
+            source.unverified(),
+            vec![location.clone(), location.replace(".dic", ".aff")]
+        );
+        source.aff_sha256 = Some("00".repeat(32));
+        assert!(source.fetch_words().is_err());
+    }
+
+    #[test]
+    fn test_frequency_checksum() {
+        let dir = tempfile::tempdir().unwrap();
+        let words_path = dir.path().join("words.txt");
+        let frequencies_path = dir.path().join("frequencies.txt");
+        fs::write(
+            &words_path,
+            "the
+thew
+",
+        )
+        .unwrap();
+        fs::write(
+            &frequencies_path,
+            "the 5000
+",
+        )
+        .unwrap();
+
+        let mut source =
+            DictionarySource::new(words_path.display().to_string(), DictFormat::Wordlist);
+        source.sha256 = Some(sha256_hex(b"the\nthew\n"));
+        source.frequency_url = Some(frequencies_path.display().to_string());
+        assert_eq!(
+            source.unverified(),
+            vec![frequencies_path.display().to_string()]
+        );
+
+        source.frequency_sha256 = Some("00".repeat(32));
+        assert!(source.fetch_words().is_err());
+
+        source.frequency_sha256 = Some(sha256_hex(b"the 5000\n"));
+        assert!(source.unverified().is_empty());
+        assert_eq!(
+            source.fetch_words().unwrap().frequencies.get("the"),
+            Some(&5000)
+        );
+    }
+
+    #[test]
+    fn test_parse_frequencies() {
+        let counted = parse_frequencies("the 5000\nThe 20\nthew 3\nbad line\n");
+        assert_eq!(counted.get("the"), Some(&5000));
+        assert_eq!(counted.get("thew"), Some(&3));
+        assert!(!counted.contains_key("bad"));
+
+        let ranked = parse_frequencies("# most common first\nthe\nof\nand\n");
+        assert_eq!(ranked.get("the"), Some(&1_000_000_000));
+        assert_eq!(ranked.get("and"), Some(&333_333_333));
+
+        // The 1000th word of a ranked list is 1000 times rarer than the first
+        let list: String = (1..=1000).map(|rank| format!("w{}\n", rank)).collect();
+        let ranked = parse_frequencies(&list);
+        assert_eq!(ranked["w1"], 1000 * ranked["w1000"]);
+    }
+}
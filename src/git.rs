@@ -0,0 +1,235 @@
+use anyhow::{Context, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+lazy_static! {
+    // Unified diff hunk header; only the new-file side is needed
+    static ref HUNK_HEADER: Regex = Regex::new(r"^@@ -\d+(?:,\d+)? \+(\d+)(?:,(\d+))? @@").unwrap();
+}
+
+#[derive(Debug, Clone)]
+pub struct CommitMessage {
+    pub id: String,
+    pub message: String,
+}
+
+impl CommitMessage {
+    /// Abbreviated commit hash for display
+    pub fn short_id(&self) -> &str {
+        &self.id[..self.id.len().min(7)]
+    }
+}
+
+/// Read the messages of all commits in a revision range (e.g. `main..HEAD`)
+pub fn commit_messages(range: &str) -> Result<Vec<CommitMessage>> {
+    // Separate hash and body with NUL, and commits with the record separator
+    let output = run(&["log", "--format=%H%x00%B%x1e", range])?;
+
+    let commits = output
+        .split('\x1e')
+        .filter_map(|record| {
+            let (id, message) = record.trim_start_matches('\n').split_once('\0')?;
+            Some(CommitMessage {
+                id: id.to_string(),
+                message: message.to_string(),
+            })
+        })
+        .collect();
+
+    Ok(commits)
+}
+
+/// Lines added or modified in the working tree or index, keyed by absolute path
+#[derive(Debug, Clone, Default)]
+pub struct ChangedLines {
+    files: HashMap<PathBuf, Vec<(usize, usize)>>,
+}
+
+impl ChangedLines {
+    /// Check whether a 1-indexed line of a file was added or modified
+    pub fn contains(&self, path: &Path, line: usize) -> bool {
+        self.file(path).contains(line)
+    }
+
+    /// The changed lines of one file, resolving its path once
+    pub fn file(&self, path: &Path) -> FileChanges<'_> {
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        FileChanges {
+            ranges: self.files.get(&path).map_or(&[], Vec::as_slice),
+        }
+    }
+
+    /// Files with at least one added or modified line
+    pub fn files(&self) -> Vec<PathBuf> {
+        let mut files: Vec<PathBuf> = self.files.keys().cloned().collect();
+        files.sort();
+        files
+    }
+}
+
+/// Added or modified line ranges of a single file
+#[derive(Debug, Clone, Copy)]
+pub struct FileChanges<'a> {
+    ranges: &'a [(usize, usize)],
+}
+
+impl FileChanges<'_> {
+    /// Check whether a 1-indexed line was added or modified
+    pub fn contains(&self, line: usize) -> bool {
+        self.ranges
+            .iter()
+            .any(|&(start, end)| line >= start && line <= end)
+    }
+}
+
+/// Canonical path of the working tree's top-level directory
+pub fn repo_root() -> Result<PathBuf> {
+    let root = run(&["rev-parse", "--show-toplevel"])?;
+    let root = PathBuf::from(root.trim());
+    Ok(root.canonicalize().unwrap_or(root))
+}
+
+/// Collect changed lines relative to `base` (default: HEAD), or of the index if `staged`
+pub fn changed_lines(base: Option<&str>, staged: bool) -> Result<ChangedLines> {
+    let root = repo_root()?;
+
+    // Pin prefixes and disable external tools so user config can't change the format
+    let mut args = vec![
+        "-c",
+        "core.quotePath=false",
+        "diff",
+        "--no-color",
+        "--no-ext-diff",
+        "--src-prefix=a/",
+        "--dst-prefix=b/",
+        "-U0",
+    ];
+    if staged {
+        args.push("--cached");
+    }
+    if let Some(base) = base {
+        args.push(base);
+    }
+
+    let diff = run(&args)?;
+    Ok(parse_diff(&diff, &root))
+}
+
+fn parse_diff(diff: &str, root: &Path) -> ChangedLines {
+    let mut changed = ChangedLines::default();
+    let mut current: Option<PathBuf> = None;
+    // Between `diff --git` and the first hunk, where `+++ ` names the file rather than
+    // an added line starting with "++ "
+    let mut in_header = false;
+
+    for line in diff.lines() {
+        if line.starts_with("diff --git ") {
+            current = None;
+            in_header = true;
+        } else if let (true, Some(path)) = (in_header, line.strip_prefix("+++ ")) {
+            // Git ends names containing spaces with a tab
+            let path = path.strip_suffix('\t').unwrap_or(path);
+            current = path.strip_prefix("b/").map(|p| root.join(p));
+        } else if let Some(caps) = HUNK_HEADER.captures(line) {
+            in_header = false;
+            let Some(path) = &current else {
+                continue;
+            };
+            let start: usize = caps[1].parse().unwrap_or(0);
+            let count: usize = caps.get(2).map_or(1, |c| c.as_str().parse().unwrap_or(0));
+
+            // Pure deletions have no lines on the new side
+            if count > 0 {
+                changed
+                    .files
+                    .entry(path.clone())
+                    .or_default()
+                    .push((start, start + count - 1));
+            }
+        }
+    }
+
+    changed
+}
+
+fn run(args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .context("Failed to run git")?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_diff() {
+        let diff = "\
+diff --git a/README.md b/README.md
+index 1111111..2222222 100644
+--- a/README.md
++++ b/README.md
+@@ -3,0 +4,2 @@ Intro
++New line one
++New line two
+@@ -10 +12 @@ Usage
+-old
++new
+@@ -20,3 +23,0 @@ End
+-gone
+diff --git a/old.txt b/old.txt
+deleted file mode 100644
+--- a/old.txt
++++ /dev/null
+@@ -1 +0,0 @@
+-removed
+";
+        let root = Path::new("/repo");
+        let changed = parse_diff(diff, root);
+
+        let readme = root.join("README.md");
+        assert!(!changed.contains(&readme, 3));
+        assert!(changed.contains(&readme, 4));
+        assert!(changed.contains(&readme, 5));
+        assert!(changed.contains(&readme, 12));
+        assert!(!changed.contains(&readme, 23));
+        assert_eq!(changed.files(), vec![readme]);
+    }
+
+    #[test]
+    fn test_parse_diff_added_plus_lines() {
+        let diff = "diff --git a/my notes.md b/my notes.md
+index 1111111..2222222 100644
+--- a/my notes.md\t
++++ b/my notes.md\t
+@@ -1,0 +2 @@ Title
++++ x
+@@ -5 +6,2 @@ End
+-old
++new
++++ y
+";
+        let root = Path::new("/repo");
+        let changed = parse_diff(diff, root);
+
+        let notes = root.join("my notes.md");
+        assert!(changed.contains(&notes, 2));
+        assert!(changed.contains(&notes, 6));
+        assert!(changed.contains(&notes, 7));
+        assert_eq!(changed.files(), vec![notes]);
+    }
+}
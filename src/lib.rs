@@ -1,15 +1,23 @@
+pub mod baseline;
 pub mod checker;
 pub mod cli;
+pub mod compat;
 pub mod config;
+pub mod consistency;
 pub mod dict;
+pub mod git;
 pub mod parser;
 
 pub use checker::SpellChecker;
 pub use config::Config;
 
+use serde::{Deserialize, Serialize};
+
 #[derive(Debug, Clone, Default)]
 pub struct CheckResult {
     pub error_count: usize,
+    /// Findings below error severity, which don't fail the run
+    pub warning_count: usize,
     pub fixed_count: usize,
     pub errors: Vec<SpellError>,
 }
@@ -21,6 +29,55 @@ pub struct SpellError {
     pub column: usize,
     pub context: String,
     pub suggestions: Vec<String>,
+    pub kind: ErrorKind,
+    pub severity: Severity,
+    /// How sure we are that the first suggestion is the intended text (0–1)
+    pub confidence: f64,
+}
+
+/// What a finding reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorKind {
+    /// Unknown or rejected word
+    #[default]
+    Misspelling,
+    /// Known word written with the wrong capitalization (e.g., "Github")
+    Capitalization,
+    /// Forbidden term with a preferred replacement (e.g., "whitelist")
+    Terminology,
+    /// Reported by the rule with this id (e.g., "check-compound")
+    Rule(&'static str),
+}
+
+impl ErrorKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorKind::Misspelling => "misspelling",
+            ErrorKind::Capitalization => "capitalization",
+            ErrorKind::Terminology => "terminology",
+            ErrorKind::Rule(id) => id,
+        }
+    }
+}
+
+/// How serious a finding is; only errors fail the run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Hint,
+    Warning,
+    #[default]
+    Error,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Hint => "hint",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -1,8 +1,13 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::{CommandFactory, Parser};
 use clap_complete::{generate, Shell};
+use spellchk::baseline::{Baseline, DEFAULT_BASELINE_FILE};
 use spellchk::cli::output::OutputFormat;
-use spellchk::{checker, cli, dict, Config};
+use spellchk::consistency::{self, TermIndex};
+use spellchk::dict::sources::{DictFormat, DictionarySource};
+use spellchk::parser::FileType;
+use spellchk::{checker, cli, dict, git, Config};
+use std::fs;
 use std::io;
 use std::path::PathBuf;
 
@@ -30,9 +35,9 @@ struct Cli {
     #[arg(long)]
     no_fail: bool,
 
-    /// Language/dictionary to use (e.g., en_US, en_GB)
-    #[arg(short, long, default_value = "en_US")]
-    language: String,
+    /// Language/dictionary to use (e.g., en_US, en_GB) [default: en_US]
+    #[arg(short, long)]
+    language: Option<String>,
 
     /// Output format (text, json)
     #[arg(short = 'o', long, default_value = "text")]
@@ -50,6 +55,18 @@ struct Cli {
     #[arg(long)]
     personal_dict: Option<PathBuf>,
 
+    /// Only report errors on lines changed relative to a git revision
+    #[arg(long, value_name = "REV")]
+    diff_base: Option<String>,
+
+    /// Only report errors on lines changed in the git index
+    #[arg(long)]
+    staged: bool,
+
+    /// Baseline file of known findings to suppress
+    #[arg(long, value_name = "FILE", default_value = DEFAULT_BASELINE_FILE)]
+    baseline: PathBuf,
+
     /// Generate shell completion script
     #[arg(long, value_name = "SHELL")]
     completion: Option<Shell>,
@@ -65,6 +82,48 @@ enum Commands {
         #[command(subcommand)]
         action: DictCommands,
     },
+    /// Check a commit message (usable as a commit-msg hook)
+    CommitMsg {
+        /// Commit message file (e.g., .git/COMMIT_EDITMSG)
+        #[arg(required_unless_present = "range", conflicts_with = "range")]
+        file: Option<PathBuf>,
+
+        /// Check messages of all commits in a revision range (e.g., main..HEAD)
+        #[arg(long, value_name = "A..B")]
+        range: Option<String>,
+    },
+    /// Baseline management
+    Baseline {
+        #[command(subcommand)]
+        action: BaselineCommands,
+    },
+    /// Show which dictionary layer accepts or rejects a word
+    Explain {
+        /// Word to look up
+        word: String,
+    },
+    /// List the built-in rules and whether they are enabled
+    Rules,
+    /// Report terms spelled or capitalized differently across files
+    Terms {
+        /// Files to compare
+        #[arg(value_name = "FILES", required = true)]
+        files: Vec<PathBuf>,
+
+        /// Add the majority spelling of each term to .spellchk.toml
+        #[arg(long)]
+        promote: bool,
+    },
+}
+
+#[derive(Parser, Debug)]
+enum BaselineCommands {
+    /// Record current findings so later runs only report new ones
+    Create {
+        /// Files to record findings for
+        #[arg(value_name = "FILES", required = true)]
+        files: Vec<PathBuf>,
+    },
 }
 
 #[derive(Parser, Debug)]
@@ -76,6 +135,31 @@ enum DictCommands {
         /// Language code (e.g., en_US, en_GB, fr_FR)
         language: String,
     },
+    /// Install a dictionary from a local file or URL
+    Import {
+        /// Language code to install as (e.g., en_US)
+        language: String,
+        /// File path, file:// URL or http(s) URL
+        source: String,
+        /// Dictionary format (wordlist, hunspell) [default: inferred from extension]
+        #[arg(long)]
+        format: Option<DictFormat>,
+        /// Expected SHA-256 of the file
+        #[arg(long)]
+        sha256: Option<String>,
+        /// Expected SHA-256 of the Hunspell .aff file
+        #[arg(long)]
+        aff_sha256: Option<String>,
+        /// License of the word list, recorded in the manifest
+        #[arg(long)]
+        license: Option<String>,
+        /// Word frequency list (`word count` per line) used to rank suggestions
+        #[arg(long, value_name = "FILE|URL")]
+        frequencies: Option<String>,
+        /// Expected SHA-256 of the frequency list
+        #[arg(long)]
+        frequencies_sha256: Option<String>,
+    },
     /// Update all dictionaries
     Update,
     /// Show dictionary info
@@ -83,6 +167,11 @@ enum DictCommands {
         /// Language code
         language: String,
     },
+    /// Verify installed dictionaries against their manifests
+    Verify {
+        /// Language code (default: all installed dictionaries)
+        language: Option<String>,
+    },
 }
 
 fn main() -> Result<()> {
@@ -96,80 +185,305 @@ fn main() -> Result<()> {
     }
 
     // Handle subcommands
-    if let Some(command) = cli.command {
-        return handle_command(command);
+    if let Some(command) = &cli.command {
+        return handle_command(command, &cli);
     }
 
     // Load configuration
-    let config = Config::load(
-        cli.language.clone(),
-        cli.personal_dict.clone(),
-        cli.ignore_pattern.clone(),
-    )?;
+    let config = load_config(&cli)?;
+
+    // Restrict checking to changed lines when diffing against git
+    let changed_lines = if cli.diff_base.is_some() || cli.staged {
+        Some(git::changed_lines(cli.diff_base.as_deref(), cli.staged)?)
+    } else {
+        None
+    };
+
+    // Default to the changed files when diffing without explicit files
+    let files = match &changed_lines {
+        Some(changed) if cli.files.is_empty() => {
+            let cwd = std::env::current_dir()?;
+            changed
+                .files()
+                .into_iter()
+                .map(|path| path.strip_prefix(&cwd).map(PathBuf::from).unwrap_or(path))
+                .collect()
+        }
+        _ => cli.files.clone(),
+    };
 
     // Validate input files
-    if cli.files.is_empty() {
+    if files.is_empty() && changed_lines.is_none() {
         anyhow::bail!("No files specified. Use --help for usage information.");
     }
 
     // Initialize checker
-    let checker = checker::SpellChecker::new(&config)?;
+    let mut checker = checker::SpellChecker::new(&config)?;
+    if let Some(changed) = changed_lines {
+        checker = checker.with_changed_lines(changed);
+    }
+    if cli.baseline.exists() {
+        checker = checker.with_baseline(Baseline::load(&cli.baseline)?);
+    }
 
     // Process files
     let mut total_errors = 0;
+    let mut total_warnings = 0;
     let mut total_fixed = 0;
 
-    for file_path in &cli.files {
+    let ignored_paths = config.ignore_path_matcher()?;
+
+    for file_path in &files {
         if !file_path.exists() {
             eprintln!("Error: File not found: {}", file_path.display());
             continue;
         }
 
+        if Config::is_path_ignored(&ignored_paths, file_path) {
+            continue;
+        }
+
         let result = if cli.fix {
             if cli.interactive {
                 checker.fix_interactive(file_path, &config, !cli.no_color)?
             } else {
-                checker.fix_auto(file_path, &config, !cli.no_color)?
+                checker.fix_auto(file_path, &config, !cli.no_color, &cli.format)?
             }
         } else {
             checker.check(file_path, &config, !cli.no_color, &cli.format)?
         };
 
         total_errors += result.error_count;
+        total_warnings += result.warning_count;
         total_fixed += result.fixed_count;
     }
 
     // Print summary
     if cli.fix {
-        cli::output::print_fix_summary(total_fixed, &cli.files, !cli.no_color);
+        cli::output::print_fix_summary(total_fixed, &files, !cli.no_color);
+        if total_errors + total_warnings > 0 {
+            cli::output::print_check_summary(total_errors, total_warnings, &files, !cli.no_color);
+        }
     } else {
-        cli::output::print_check_summary(total_errors, &cli.files, !cli.no_color);
+        cli::output::print_stale_baseline(&checker.stale_baseline_entries(), !cli.no_color);
+        cli::output::print_check_summary(total_errors, total_warnings, &files, !cli.no_color);
     }
 
     // Exit with appropriate code
-    if total_errors > 0 && !cli.no_fail && !cli.fix {
+    if total_errors > 0 && !cli.no_fail {
         std::process::exit(1);
     }
 
     Ok(())
 }
 
-fn handle_command(command: Commands) -> Result<()> {
+fn load_config(cli: &Cli) -> Result<Config> {
+    Config::load(
+        cli.language.clone(),
+        cli.personal_dict.clone(),
+        cli.ignore_pattern.clone(),
+    )
+}
+
+fn handle_command(command: &Commands, cli: &Cli) -> Result<()> {
     match command {
         Commands::Dict { action } => match action {
             DictCommands::List => {
                 dict::manager::list_dictionaries()?;
             }
             DictCommands::Download { language } => {
-                dict::manager::download_dictionary(&language)?;
+                let config = load_config(cli)?;
+                dict::manager::download_dictionary(language, &config.dictionary_sources())?;
+            }
+            DictCommands::Import {
+                language,
+                source,
+                format,
+                sha256,
+                aff_sha256,
+                license,
+                frequencies,
+                frequencies_sha256,
+            } => {
+                let mut source = DictionarySource::new(
+                    source.as_str(),
+                    format.unwrap_or_else(|| DictFormat::infer(source)),
+                );
+                source.sha256 = sha256.clone();
+                source.aff_sha256 = aff_sha256.clone();
+                source.license = license.clone();
+                source.frequency_url = frequencies.clone();
+                source.frequency_sha256 = frequencies_sha256.clone();
+                dict::manager::import_dictionary(language, &source)?;
             }
             DictCommands::Update => {
-                dict::manager::update_dictionaries()?;
+                let config = load_config(cli)?;
+                dict::manager::update_dictionaries(&config.dictionary_sources())?;
             }
             DictCommands::Info { language } => {
-                dict::manager::show_info(&language)?;
+                dict::manager::show_info(language)?;
+            }
+            DictCommands::Verify { language } => {
+                dict::manager::verify_dictionaries(language.as_deref())?;
+            }
+        },
+        Commands::CommitMsg { file, range } => {
+            check_commit_messages(file.as_ref(), range.as_deref(), cli)?;
+        }
+        Commands::Baseline { action } => match action {
+            BaselineCommands::Create { files } => {
+                create_baseline(files, cli)?;
             }
         },
+        Commands::Explain { word } => {
+            let config = load_config(cli)?;
+            let checker = checker::SpellChecker::new(&config)?;
+            cli::output::print_explanation(&checker.explain(word), !cli.no_color);
+        }
+        Commands::Rules => {
+            let config = load_config(cli)?;
+            cli::output::print_rules(&checker::rules::statuses(&config), !cli.no_color);
+        }
+        Commands::Terms { files, promote } => {
+            report_terms(files, *promote, cli)?;
+        }
     }
     Ok(())
 }
+
+fn create_baseline(files: &[PathBuf], cli: &Cli) -> Result<()> {
+    let config = load_config(cli)?;
+    let checker = checker::SpellChecker::new(&config)?;
+    let ignored_paths = config.ignore_path_matcher()?;
+    let mut baseline = Baseline::default();
+
+    for file_path in files {
+        if !file_path.exists() {
+            eprintln!("Error: File not found: {}", file_path.display());
+            continue;
+        }
+        if Config::is_path_ignored(&ignored_paths, file_path) {
+            continue;
+        }
+
+        let content = fs::read_to_string(file_path)
+            .with_context(|| format!("Failed to read file: {}", file_path.display()))?;
+        let errors =
+            checker.find_errors(file_path, &content, FileType::from_path(file_path), &config)?;
+        baseline.add(file_path, &content, &errors);
+    }
+
+    baseline.save(&cli.baseline)?;
+    println!(
+        "Recorded {} {} in {}",
+        baseline.entries.len(),
+        if baseline.entries.len() == 1 {
+            "finding"
+        } else {
+            "findings"
+        },
+        cli.baseline.display()
+    );
+
+    Ok(())
+}
+
+fn report_terms(files: &[PathBuf], promote: bool, cli: &Cli) -> Result<()> {
+    let config = load_config(cli)?;
+    let checker = checker::SpellChecker::new(&config)?;
+    let ignored_paths = config.ignore_path_matcher()?;
+    let mut index = TermIndex::default();
+
+    for file_path in files {
+        if !file_path.exists() {
+            eprintln!("Error: File not found: {}", file_path.display());
+            continue;
+        }
+        if Config::is_path_ignored(&ignored_paths, file_path) {
+            continue;
+        }
+
+        let content = fs::read_to_string(file_path)
+            .with_context(|| format!("Failed to read file: {}", file_path.display()))?;
+        let spans = spellchk::parser::parse_file(file_path, &content)?;
+        index.add(file_path, &content, &spans, &|word| checker.is_known(word));
+    }
+
+    let groups = index.inconsistent();
+    cli::output::print_term_groups(&groups, !cli.no_color);
+
+    if promote && !groups.is_empty() {
+        let config_path = PathBuf::from(".spellchk.toml");
+        let promotion = consistency::promote(&config_path, &groups)?;
+        println!(
+            "\nAdded {} preferred {} and {} {} to {}",
+            promotion.terms,
+            if promotion.terms == 1 {
+                "term"
+            } else {
+                "terms"
+            },
+            promotion.words,
+            if promotion.words == 1 {
+                "word"
+            } else {
+                "words"
+            },
+            config_path.display()
+        );
+        if promotion.words > 0 && !config.case_sensitive {
+            println!("Set case_sensitive = true to flag other casings of listed words.");
+        }
+    }
+
+    Ok(())
+}
+
+fn check_commit_messages(file: Option<&PathBuf>, range: Option<&str>, cli: &Cli) -> Result<()> {
+    let config = load_config(cli)?;
+    let checker = checker::SpellChecker::new(&config)?;
+    let colored = !cli.no_color;
+
+    let mut total_errors = 0;
+    let mut total_warnings = 0;
+    let mut checked = Vec::new();
+
+    if let Some(range) = range {
+        for commit in git::commit_messages(range)? {
+            let label = PathBuf::from(format!("commit {}", commit.short_id()));
+            let result = checker.check_content(
+                &label,
+                &commit.message,
+                FileType::CommitMessage,
+                &config,
+                colored,
+                &cli.format,
+            )?;
+            total_errors += result.error_count;
+            total_warnings += result.warning_count;
+            checked.push(label);
+        }
+    } else if let Some(file) = file {
+        let content = fs::read_to_string(file)
+            .with_context(|| format!("Failed to read file: {}", file.display()))?;
+        let result = checker.check_content(
+            file,
+            &content,
+            FileType::CommitMessage,
+            &config,
+            colored,
+            &cli.format,
+        )?;
+        total_errors += result.error_count;
+        total_warnings += result.warning_count;
+        checked.push(file.clone());
+    }
+
+    cli::output::print_check_summary(total_errors, total_warnings, &checked, colored);
+
+    if total_errors > 0 && !cli.no_fail {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
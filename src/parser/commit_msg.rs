@@ -0,0 +1,111 @@
+use crate::parser::{plaintext, TextSpan};
+use anyhow::Result;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashSet;
+
+lazy_static! {
+    // Git trailer lines, e.g. "Signed-off-by: Name <email>" or "Fixes: #123"
+    static ref TRAILER: Regex = Regex::new(r"^[A-Za-z0-9][A-Za-z0-9-]*:\s+\S").unwrap();
+}
+
+/// Marker git writes above the diff when `commit --verbose` is used
+const SCISSORS: &str = "------------------------ >8 ------------------------";
+
+/// Parse a commit message, skipping comments, the scissors section, trailers and diffs
+pub fn parse(content: &str) -> Result<Vec<TextSpan>> {
+    let skipped = skipped_lines(content);
+
+    let spans = plaintext::parse(content)?
+        .into_iter()
+        .filter(|span| !skipped.contains(&span.line))
+        .collect();
+
+    Ok(spans)
+}
+
+/// Return the 1-indexed line numbers that are not part of the message body
+fn skipped_lines(content: &str) -> HashSet<usize> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut skipped = HashSet::new();
+
+    // Everything from the scissors line or the start of a diff is git-generated
+    let end = lines
+        .iter()
+        .position(|line| {
+            (line.starts_with('#') && line.contains(SCISSORS)) || line.starts_with("diff --git ")
+        })
+        .unwrap_or(lines.len());
+    skipped.extend(end + 1..=lines.len());
+
+    // Comment lines
+    for (i, line) in lines[..end].iter().enumerate() {
+        if line.starts_with('#') {
+            skipped.insert(i + 1);
+        }
+    }
+
+    // Trailers: the last paragraph, if every non-comment line in it is a trailer
+    let body: Vec<usize> = (0..end).filter(|i| !skipped.contains(&(i + 1))).collect();
+    let last_content = body.iter().rposition(|&i| !lines[i].trim().is_empty());
+    if let Some(last) = last_content {
+        let mut first = last;
+        while first > 0 && !lines[body[first - 1]].trim().is_empty() {
+            first -= 1;
+        }
+
+        // The subject line is never a trailer block
+        let is_subject = first == 0;
+        let paragraph = &body[first..=last];
+        if !is_subject && paragraph.iter().all(|&i| TRAILER.is_match(lines[i])) {
+            skipped.extend(paragraph.iter().map(|i| i + 1));
+        }
+    }
+
+    skipped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(content: &str) -> Vec<String> {
+        parse(content)
+            .unwrap()
+            .into_iter()
+            .map(|s| s.text)
+            .collect()
+    }
+
+    #[test]
+    fn test_skips_comments_and_trailers() {
+        let content = "Fix tokenizer offsets\n\nExplain the change here.\n\nSigned-off-by: Jane Doe <jane@example.com>\nReviewed-by: Someone Else\n# Please enter the commit message\n";
+        let words = words(content);
+
+        assert!(words.contains(&"tokenizer".to_string()));
+        assert!(words.contains(&"Explain".to_string()));
+        assert!(!words.contains(&"Jane".to_string()));
+        assert!(!words.contains(&"Someone".to_string()));
+        assert!(!words.contains(&"Please".to_string()));
+    }
+
+    #[test]
+    fn test_skips_scissors_section() {
+        let content = "Add feature\n\n# ------------------------ >8 ------------------------\n# Do not modify or remove the line above.\ndiff --git a/src/lib.rs b/src/lib.rs\n+fn mispeled() {}\n";
+        let words = words(content);
+
+        assert_eq!(words, vec!["Add", "feature"]);
+    }
+
+    #[test]
+    fn test_subject_is_not_a_trailer() {
+        let words = words("docs: update readme\n");
+        assert!(words.contains(&"update".to_string()));
+    }
+
+    #[test]
+    fn test_preserves_line_numbers() {
+        let spans = parse("# comment\nSubject line\n").unwrap();
+        assert_eq!(spans[0].line, 2);
+    }
+}
@@ -0,0 +1,173 @@
+use crate::parser::{FileType, SourceLang};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashSet;
+
+lazy_static! {
+    // e.g. "spellchk:disable", "cSpell:words kubectl etcd", "codespell:ignore"
+    static ref DIRECTIVE: Regex =
+        Regex::new(r"(?i)\b(spellchk|cspell|codespell)\s*:\s*([a-z-]+)").unwrap();
+}
+
+/// Inline suppression directives found in a file's comments
+#[derive(Debug, Clone, Default)]
+pub struct Directives {
+    ignored_lines: HashSet<usize>,
+    disabled_ranges: Vec<(usize, usize)>,
+    words: HashSet<String>,
+}
+
+impl Directives {
+    /// Check whether a 1-indexed line is excluded from checking
+    pub fn is_suppressed(&self, line: usize) -> bool {
+        self.ignored_lines.contains(&line)
+            || self
+                .disabled_ranges
+                .iter()
+                .any(|&(start, end)| line >= start && line <= end)
+    }
+
+    /// Check whether a lowercased word was declared with a `words` directive
+    pub fn allows_word(&self, word: &str) -> bool {
+        self.words.contains(word)
+    }
+}
+
+/// Collect directives from the comments of a file
+pub fn parse(content: &str, file_type: FileType) -> Directives {
+    let mut directives = Directives::default();
+    let mut disabled_since = None;
+
+    for (line_num, line) in content.lines().enumerate() {
+        let line_num = line_num + 1;
+
+        for cap in DIRECTIVE.captures_iter(line) {
+            let whole = cap.get(0).unwrap();
+            if !in_comment(&line[..whole.start()], file_type) {
+                continue;
+            }
+
+            let args = directive_args(&line[whole.end()..]);
+            let tool = cap[1].to_lowercase();
+            let name = cap[2].to_lowercase();
+            match (tool.as_str(), name.as_str()) {
+                // codespell ignores the line carrying the directive
+                ("codespell", "ignore") => {}
+                ("codespell", _) => continue,
+                (_, "ignore-next-line" | "disable-next-line") => {
+                    directives.ignored_lines.insert(line_num + 1);
+                }
+                (_, "disable-line") => {}
+                (_, "disable") => {
+                    disabled_since.get_or_insert(line_num);
+                }
+                (_, "enable") => {
+                    if let Some(start) = disabled_since.take() {
+                        directives.disabled_ranges.push((start, line_num));
+                    }
+                }
+                // cspell's "ignore" accepts words the same way
+                (_, "words" | "ignore") => {
+                    directives
+                        .words
+                        .extend(args.iter().map(|w| w.to_lowercase()));
+                }
+                _ => continue,
+            }
+
+            // The directive's own words are never spelling mistakes
+            directives.ignored_lines.insert(line_num);
+        }
+    }
+
+    // An unterminated disable runs to the end of the file
+    if let Some(start) = disabled_since {
+        directives.disabled_ranges.push((start, usize::MAX));
+    }
+
+    directives
+}
+
+/// Check whether text preceding a directive opens a comment for this file type
+fn in_comment(before: &str, file_type: FileType) -> bool {
+    match file_type {
+        FileType::Markdown => before.contains("<!--"),
+        FileType::SourceCode(SourceLang::Python) => before.contains('#'),
+        FileType::SourceCode(_) => {
+            before.contains("//") || before.contains("/*") || before.trim_start().starts_with('*')
+        }
+        FileType::PlainText | FileType::CommitMessage => true,
+    }
+}
+
+/// Whitespace-separated arguments up to the end of the comment
+fn directive_args(rest: &str) -> Vec<&str> {
+    let end = ["-->", "*/"]
+        .iter()
+        .filter_map(|close| rest.find(close))
+        .min()
+        .unwrap_or(rest.len());
+
+    rest[..end]
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|w| !w.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ignore_next_line() {
+        let content = "// spellchk:ignore-next-line\nlet x = \"wrng\";\nlet y = \"wrng\";\n";
+        let directives = parse(content, FileType::SourceCode(SourceLang::Rust));
+
+        assert!(directives.is_suppressed(1));
+        assert!(directives.is_suppressed(2));
+        assert!(!directives.is_suppressed(3));
+    }
+
+    #[test]
+    fn test_disable_enable_regions() {
+        let content = "a\n<!-- spellchk:disable -->\nb\n<!-- spellchk:enable -->\nc\n<!-- spellchk:disable -->\nd\n";
+        let directives = parse(content, FileType::Markdown);
+
+        assert!(!directives.is_suppressed(1));
+        assert!(directives.is_suppressed(3));
+        assert!(!directives.is_suppressed(5));
+        assert!(directives.is_suppressed(7));
+    }
+
+    #[test]
+    fn test_words() {
+        let content = "# spellchk:words kubectl, Etcd\nimport os\n";
+        let directives = parse(content, FileType::SourceCode(SourceLang::Python));
+
+        assert!(directives.allows_word("kubectl"));
+        assert!(directives.allows_word("etcd"));
+        assert!(!directives.allows_word("import"));
+    }
+
+    #[test]
+    fn test_cspell_directives() {
+        let content = "// cSpell:disable\nfoo\n// cspell:enable\n// cspell:ignore zlib\n// cspell:disable-next-line\nbar\nbaz // codespell:ignore\n";
+        let directives = parse(content, FileType::SourceCode(SourceLang::Go));
+
+        assert!(directives.is_suppressed(2));
+        assert!(directives.allows_word("zlib"));
+        assert!(directives.is_suppressed(6));
+        assert!(directives.is_suppressed(7));
+        assert!(!directives.is_suppressed(8));
+    }
+
+    #[test]
+    fn test_requires_comment() {
+        let content = "let s = \"spellchk:disable\";\n";
+        let directives = parse(content, FileType::SourceCode(SourceLang::Rust));
+        assert!(!directives.is_suppressed(2));
+
+        let markdown = parse("spellchk:disable in prose\nmore\n", FileType::Markdown);
+        assert!(!markdown.is_suppressed(2));
+    }
+}
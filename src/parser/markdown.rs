@@ -5,14 +5,16 @@ use pulldown_cmark::{Event, Parser, Tag, TagEnd};
 /// Parse markdown and extract checkable text (skip code blocks, inline code, URLs)
 pub fn parse(content: &str) -> Result<Vec<TextSpan>> {
     let mut spans = Vec::new();
-    let parser = Parser::new(content);
+    let parser = Parser::new(content).into_offset_iter();
+
+    // Byte offset at which each line starts
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(content.match_indices('\n').map(|(i, _)| i + 1))
+        .collect();
 
     let mut in_code_block = false;
-    let mut in_inline_code = false;
-    let mut current_line = 1;
-    let mut current_column = 1;
 
-    for event in parser {
+    for (event, range) in parser {
         match event {
             Event::Start(Tag::CodeBlock(_)) => {
                 in_code_block = true;
@@ -20,39 +22,81 @@ pub fn parse(content: &str) -> Result<Vec<TextSpan>> {
             Event::End(TagEnd::CodeBlock) => {
                 in_code_block = false;
             }
-            Event::Code(_) => {
-                in_inline_code = true;
-            }
-            Event::Text(text) if !in_code_block && !in_inline_code => {
-                // Extract words from text
-                let words = extract_words(&text);
-                for (word, offset) in words {
+            Event::Text(text) if !in_code_block => {
+                // Escapes and entities make the source differ from the text;
+                // fall back to locating each word within the source range
+                let source = &content[range.clone()];
+                let verbatim = source == &*text;
+                let mut search_from = 0;
+
+                for (word, offset) in extract_words(&text) {
+                    let relative = if verbatim {
+                        Some(offset)
+                    } else {
+                        source[search_from..].find(&word).map(|i| search_from + i)
+                    };
+                    let start = relative.map_or(range.start, |r| range.start + r);
+                    if let Some(relative) = relative {
+                        search_from = relative + word.len();
+                    }
+
+                    let line = line_starts.partition_point(|&s| s <= start);
                     spans.push(TextSpan {
                         text: word.clone(),
-                        line: current_line,
-                        column: current_column + offset,
-                        start: 0, // TODO: Calculate accurate byte offsets for markdown
-                        end: 0,   // For now, fix mode works better with plain text
+                        line,
+                        column: start - line_starts[line - 1] + 1,
+                        start,
+                        // Unlocated words get an empty range so fixes skip them
+                        end: if relative.is_some() {
+                            start + word.len()
+                        } else {
+                            start
+                        },
                         original_text: get_context(&text, offset, word.len()),
                     });
                 }
-                // Update position tracking (approximate)
-                current_line += text.matches('\n').count();
-                if let Some(last_newline) = text.rfind('\n') {
-                    current_column = text.len() - last_newline;
-                } else {
-                    current_column += text.len();
-                }
             }
             _ => {}
         }
+    }
 
-        if in_inline_code {
-            in_inline_code = false;
+    Ok(spans)
+}
+
+/// Byte offsets where block-level elements (paragraphs, headings, list items, table
+/// cells, ...) start or end, in ascending order
+pub fn block_boundaries(content: &str) -> Vec<usize> {
+    let mut boundaries = Vec::new();
+
+    for (event, range) in Parser::new(content).into_offset_iter() {
+        let inline = match &event {
+            Event::Start(tag) => matches!(
+                tag,
+                Tag::Emphasis
+                    | Tag::Strong
+                    | Tag::Strikethrough
+                    | Tag::Link { .. }
+                    | Tag::Image { .. }
+            ),
+            Event::End(tag) => matches!(
+                tag,
+                TagEnd::Emphasis
+                    | TagEnd::Strong
+                    | TagEnd::Strikethrough
+                    | TagEnd::Link
+                    | TagEnd::Image
+            ),
+            _ => continue,
+        };
+        if !inline {
+            boundaries.push(range.start);
+            boundaries.push(range.end);
         }
     }
 
-    Ok(spans)
+    boundaries.sort_unstable();
+    boundaries.dedup();
+    boundaries
 }
 
 fn extract_words(text: &str) -> Vec<(String, usize)> {
@@ -128,6 +172,29 @@ More text with `inline_code` here.
         assert!(code_words.is_empty());
     }
 
+    #[test]
+    fn test_markdown_positions() {
+        let content = "# Title\n\nSome *emphasized* text &amp; more.\n";
+        let spans = parse(content).unwrap();
+
+        for span in &spans {
+            assert_eq!(&content[span.start..span.end], span.text);
+        }
+        let more = spans.iter().find(|s| s.text == "more").unwrap();
+        assert_eq!((more.line, more.column), (3, 30));
+    }
+
+    #[test]
+    fn test_block_boundaries() {
+        let content = "# Title\nSome *text* here\n";
+        let boundaries = block_boundaries(content);
+
+        // The heading and the paragraph, but not the emphasis
+        assert!(boundaries.contains(&0));
+        assert!(boundaries.contains(&8));
+        assert!(!boundaries.contains(&13));
+    }
+
     #[test]
     fn test_word_extraction() {
         let text = "Hello, world! This is a test.";
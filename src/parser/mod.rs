@@ -1,3 +1,5 @@
+pub mod commit_msg;
+pub mod directives;
 pub mod markdown;
 pub mod plaintext;
 pub mod source_code;
@@ -10,6 +12,7 @@ pub enum FileType {
     Markdown,
     SourceCode(SourceLang),
     PlainText,
+    CommitMessage,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -30,6 +33,11 @@ pub enum SourceLang {
 impl FileType {
     /// Detect file type from extension
     pub fn from_path(path: &Path) -> Self {
+        let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        if matches!(file_name, "COMMIT_EDITMSG" | "MERGE_MSG" | "TAG_EDITMSG") {
+            return FileType::CommitMessage;
+        }
+
         let ext = path
             .extension()
             .and_then(|e| e.to_str())
@@ -65,12 +73,16 @@ pub struct TextSpan {
 
 /// Parse a file and extract checkable text spans
 pub fn parse_file(path: &Path, content: &str) -> Result<Vec<TextSpan>> {
-    let file_type = FileType::from_path(path);
+    parse_content(FileType::from_path(path), content)
+}
 
+/// Parse content as the given file type
+pub fn parse_content(file_type: FileType, content: &str) -> Result<Vec<TextSpan>> {
     match file_type {
         FileType::Markdown => markdown::parse(content),
         FileType::SourceCode(lang) => source_code::parse(content, lang),
         FileType::PlainText => plaintext::parse(content),
+        FileType::CommitMessage => commit_msg::parse(content),
     }
 }
 
@@ -97,5 +109,9 @@ mod tests {
             FileType::from_path(&PathBuf::from("notes.txt")),
             FileType::PlainText
         );
+        assert_eq!(
+            FileType::from_path(&PathBuf::from(".git/COMMIT_EDITMSG")),
+            FileType::CommitMessage
+        );
     }
 }
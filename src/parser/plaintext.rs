@@ -1,4 +1,4 @@
-use crate::checker::tokenizer::split_compound_word;
+use crate::checker::tokenizer::split_compound_word_at;
 use crate::parser::TextSpan;
 use anyhow::Result;
 use unicode_segmentation::UnicodeSegmentation;
@@ -49,10 +49,10 @@ fn extract_words(text: &str) -> Vec<(String, usize)> {
             current_word.push_str(grapheme);
         } else if !current_word.is_empty() {
             // Split camelCase and snake_case
-            let split_words = split_compound_word(&current_word);
-            for split_word in split_words {
+            let split_words = split_compound_word_at(&current_word);
+            for (split_word, offset) in split_words {
                 if split_word.len() > 1 {
-                    words.push((split_word, word_start));
+                    words.push((split_word, word_start + offset));
                 }
             }
             current_word.clear();
@@ -63,10 +63,10 @@ fn extract_words(text: &str) -> Vec<(String, usize)> {
 
     // Handle last word
     if !current_word.is_empty() {
-        let split_words = split_compound_word(&current_word);
-        for split_word in split_words {
+        let split_words = split_compound_word_at(&current_word);
+        for (split_word, offset) in split_words {
             if split_word.len() > 1 {
-                words.push((split_word, word_start));
+                words.push((split_word, word_start + offset));
             }
         }
     }
@@ -93,6 +93,7 @@ fn get_context(line: &str, offset: usize, word_len: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::checker::tokenizer::split_compound_word;
 
     #[test]
     fn test_plain_text_parsing() {
@@ -118,6 +119,24 @@ mod tests {
         assert_eq!(words, vec!["snake", "case", "word"]);
     }
 
+    #[test]
+    fn test_compound_piece_offsets() {
+        let spans = parse("on GitHub today").unwrap();
+        let pieces: Vec<(&str, usize, usize)> = spans
+            .iter()
+            .map(|s| (s.text.as_str(), s.column, s.start))
+            .collect();
+        assert_eq!(
+            pieces,
+            vec![
+                ("on", 1, 0),
+                ("Git", 4, 3),
+                ("Hub", 7, 6),
+                ("today", 11, 10)
+            ]
+        );
+    }
+
     #[test]
     fn test_multiline() {
         let content = "First line\nSecond line\nThird line";